@@ -0,0 +1,160 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Bound, BoundType, Exclusive, Inclusive, Interval};
+use core::ops::{Add, Sub};
+
+macro_rules! impl_bound_ops {
+    ($Op:ident, $op:ident) => {
+        impl<T: $Op<Output = T>> $Op for Bound<T, Inclusive> {
+            type Output = Self;
+            fn $op(self, rhs: Self) -> Self {
+                Inclusive.at(self.limit.$op(rhs.limit))
+            }
+        }
+        impl<T: $Op<Output = T>> $Op for Bound<T, Exclusive> {
+            type Output = Self;
+            fn $op(self, rhs: Self) -> Self {
+                Exclusive.at(self.limit.$op(rhs.limit))
+            }
+        }
+        /// The result is inclusive only if both operands are inclusive.
+        impl<T: $Op<Output = T>> $Op for Bound<T, BoundType> {
+            type Output = Self;
+            fn $op(self, rhs: Self) -> Self {
+                let bound_type = match (self.bound_type, rhs.bound_type) {
+                    (BoundType::Inclusive, BoundType::Inclusive) => BoundType::Inclusive,
+                    _ => BoundType::Exclusive,
+                };
+                bound_type.at(self.limit.$op(rhs.limit))
+            }
+        }
+    };
+}
+impl_bound_ops!(Add, add);
+impl_bound_ops!(Sub, sub);
+
+/// Interval arithmetic, i.e., *[a, b] + [c, d] = [a + c, b + d]*.
+/// Each boundary of the result is inclusive only if both boundaries contributing to it are inclusive.
+/// Panics if rounding errors make the result empty.
+/// ```
+/// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+/// let a = Inclusive.at(1.0).to(Exclusive.at(2.0));
+/// let b = Inclusive.at(10.0).to(Exclusive.at(20.0));
+/// assert_eq!(a + b, Inclusive.at(11.0).to(Exclusive.at(22.0)));
+///
+/// let a: Interval<i32, BoundType> = Inclusive.at(0).to(Inclusive.at(1)).into();
+/// let b: Interval<i32, BoundType> = Exclusive.at(0).to(Inclusive.at(1)).into();
+/// assert_eq!((a + b).kind(), (BoundType::Exclusive, BoundType::Inclusive));
+/// ```
+impl<T, L, R> Add for Interval<T, L, R>
+where
+    T: PartialOrd,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    Bound<T, L>: Add<Output = Bound<T, L>>,
+    Bound<T, R>: Add<Output = Bound<T, R>>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Interval::new(self.left.0 + rhs.left.0, self.right.0 + rhs.right.0)
+    }
+}
+
+/// Interval arithmetic, i.e., *[a, b] - [c, d] = [a - d, b - c]*.
+/// Each boundary of the result is inclusive only if both boundaries contributing to it are inclusive.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive};
+/// let a = Inclusive.between(1, 2);
+/// let b = Inclusive.between(10, 20);
+/// assert_eq!(a - b, Inclusive.between(-19, -8));
+/// assert_eq!(b - a, Inclusive.between(8, 19));
+/// ```
+impl<T, B> Sub for Interval<T, B, B>
+where
+    T: PartialOrd,
+    B: BoundaryOf<Left> + BoundaryOf<Right>,
+    Bound<T, B>: Sub<Output = Bound<T, B>>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Interval::new(self.left.0 - rhs.right.0, self.right.0 - rhs.left.0)
+    }
+}
+
+macro_rules! impl_outward_ops {
+    ($($T:ident),*) => {
+        $(
+            impl Interval<$T> {
+                /// Same as `+` but the boundaries are rounded outward, i.e., the lower toward *-∞* and the upper toward *+∞*,
+                /// so that the result encloses the exact sum under floating point arithmetic.
+                /// A boundary is moved by one ulp only if the rounded sum is inexact.
+                /// ```
+                /// use inter_val::Inclusive;
+                #[doc = concat!("let a = Inclusive.between(0.1", stringify!($T), ", 0.2);")]
+                #[doc = concat!("let b = Inclusive.between(0.2", stringify!($T), ", 0.3);")]
+                /// let c = a.add_outward(b);
+                /// assert!(c.inf() <= &(0.1 + 0.2) && c.sup() >= &(0.2 + 0.3));
+                /// assert_ne!(c, a + b); // 0.1 + 0.2 is inexact
+                ///
+                /// // Exact sums are kept as they are.
+                #[doc = concat!("let a = Inclusive.between(1.0", stringify!($T), ", 2.0);")]
+                /// assert_eq!(a.add_outward(a), Inclusive.between(2.0, 4.0));
+                /// ```
+                pub fn add_outward(self, rhs: Self) -> Self {
+                    Interval::new(
+                        Inclusive.at(outward::$T::add_down(*self.inf(), *rhs.inf())),
+                        Inclusive.at(outward::$T::add_up(*self.sup(), *rhs.sup())),
+                    )
+                }
+
+                /// Same as `-` but the boundaries are rounded outward like `add_outward()`.
+                /// ```
+                /// use inter_val::Inclusive;
+                #[doc = concat!("let a = Inclusive.between(0.3", stringify!($T), ", 0.5);")]
+                #[doc = concat!("let b = Inclusive.between(0.1", stringify!($T), ", 0.2);")]
+                /// let c = a.sub_outward(b);
+                /// assert!(c.inf() <= &(0.3 - 0.2) && c.sup() >= &(0.5 - 0.1));
+                /// ```
+                pub fn sub_outward(self, rhs: Self) -> Self {
+                    Interval::new(
+                        Inclusive.at(outward::$T::add_down(*self.inf(), -*rhs.sup())),
+                        Inclusive.at(outward::$T::add_up(*self.sup(), -*rhs.inf())),
+                    )
+                }
+            }
+        )*
+
+        mod outward {
+            $(
+                pub mod $T {
+                    /// Rounding error of `a + b`, i.e., exact sum minus rounded sum (TwoSum).
+                    fn error(a: $T, b: $T, sum: $T) -> $T {
+                        let b_virtual = sum - a;
+                        (a - (sum - b_virtual)) + (b - b_virtual)
+                    }
+                    pub fn add_down(a: $T, b: $T) -> $T {
+                        let sum = a + b;
+                        if sum == $T::INFINITY && a.is_finite() && b.is_finite() {
+                            $T::MAX
+                        } else if error(a, b, sum) < 0.0 {
+                            sum.next_down()
+                        } else {
+                            sum
+                        }
+                    }
+                    pub fn add_up(a: $T, b: $T) -> $T {
+                        let sum = a + b;
+                        if sum == $T::NEG_INFINITY && a.is_finite() && b.is_finite() {
+                            $T::MIN
+                        } else if error(a, b, sum) > 0.0 {
+                            sum.next_up()
+                        } else {
+                            sum
+                        }
+                    }
+                }
+            )*
+        }
+    };
+}
+impl_outward_ops!(f32, f64);
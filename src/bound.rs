@@ -1,5 +1,5 @@
 use crate::{
-    traits::{Ceil, Flip, Floor, IntoGeneral},
+    traits::{Ceil, Flip, Floor, Infinity, IntoGeneral},
     BoundType, Exclusive, Inclusive,
 };
 
@@ -36,6 +36,21 @@ impl<T, B> Bound<T, B> {
             bound_type: self.bound_type,
         }
     }
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Bound<U, B> {
+        Bound {
+            limit: f(self.limit),
+            bound_type: self.bound_type,
+        }
+    }
+    pub fn as_ref(&self) -> Bound<&T, B>
+    where
+        B: Copy,
+    {
+        Bound {
+            limit: &self.limit,
+            bound_type: self.bound_type,
+        }
+    }
 }
 impl<T: num::NumCast, B> Bound<T, B> {
     pub fn try_cast<U: num::NumCast>(self) -> Option<Bound<U, B>> {
@@ -92,6 +107,12 @@ impl_ceil_floor_for_integer!(i32);
 impl_ceil_floor_for_integer!(i64);
 impl_ceil_floor_for_integer!(i128);
 impl_ceil_floor_for_integer!(isize);
+impl_ceil_floor_for_integer!(u8);
+impl_ceil_floor_for_integer!(u16);
+impl_ceil_floor_for_integer!(u32);
+impl_ceil_floor_for_integer!(u64);
+impl_ceil_floor_for_integer!(u128);
+impl_ceil_floor_for_integer!(usize);
 
 fn ceil_exclusive<T: num::Float>(t: T) -> T {
     let ceil = t.ceil();
@@ -115,7 +136,7 @@ macro_rules! impl_ceil_floor_for_float {
     ($T:ty) => {
         impl Ceil<$T> for Bound<$T, Inclusive> {
             fn ceil(&self) -> $T {
-                self.limit.ceil()
+                num::Float::ceil(self.limit)
             }
         }
         impl Ceil<$T> for Bound<$T, Exclusive> {
@@ -126,14 +147,14 @@ macro_rules! impl_ceil_floor_for_float {
         impl Ceil<$T> for Bound<$T, BoundType> {
             fn ceil(&self) -> $T {
                 match self.bound_type {
-                    BoundType::Inclusive => self.limit.ceil(),
+                    BoundType::Inclusive => num::Float::ceil(self.limit),
                     BoundType::Exclusive => ceil_exclusive(self.limit),
                 }
             }
         }
         impl Floor<$T> for Bound<$T, Inclusive> {
             fn floor(&self) -> $T {
-                self.limit.floor()
+                num::Float::floor(self.limit)
             }
         }
         impl Floor<$T> for Bound<$T, Exclusive> {
@@ -144,7 +165,7 @@ macro_rules! impl_ceil_floor_for_float {
         impl Floor<$T> for Bound<$T, BoundType> {
             fn floor(&self) -> $T {
                 match self.bound_type {
-                    BoundType::Inclusive => self.limit.floor(),
+                    BoundType::Inclusive => num::Float::floor(self.limit),
                     BoundType::Exclusive => floor_exclusive(self.limit),
                 }
             }
@@ -153,3 +174,19 @@ macro_rules! impl_ceil_floor_for_float {
 }
 impl_ceil_floor_for_float!(f32);
 impl_ceil_floor_for_float!(f64);
+
+macro_rules! impl_infinity_for_float {
+    ($($T:ty),*) => {
+        $(
+            impl Infinity for $T {
+                fn neg_infinity() -> Self {
+                    <$T>::NEG_INFINITY
+                }
+                fn infinity() -> Self {
+                    <$T>::INFINITY
+                }
+            }
+        )*
+    };
+}
+impl_infinity_for_float!(f32, f64);
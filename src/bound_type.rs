@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crate::traits::{Boundary, BoundaryOf, Flip, IntoGeneral};
 
@@ -8,24 +8,41 @@ pub struct Inclusive;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct Exclusive;
 
+/// Boundary at infinity, i.e., *-∞* as a left boundary and *+∞* as a right boundary.
+/// It is created only by [`Unbounded::neg_infinity()`] and [`Unbounded::infinity()`],
+/// so that its limit is always given by [`Infinity`](crate::traits::Infinity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Unbounded(pub(crate) ());
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BoundType {
     Inclusive,
     Exclusive,
 }
 
+/// Marker of the left side, used as `BoundaryOf<Left>`.
 #[derive(Debug, Clone, Copy)]
 pub struct Left;
 
+/// Marker of the right side, used as `BoundaryOf<Right>`.
 #[derive(Debug, Clone, Copy)]
 pub struct Right;
 
+/// Boundary type `B` ordered as a boundary on the side `S`, given by `BoundaryOf::into_ordered()`.
+/// On the left side an inclusive boundary comes before an exclusive one, and on the right side after it.
+/// ```
+/// use inter_val::{BoundType, BoundaryOf, Left, Right, SideInclusion};
+/// let left: SideInclusion<BoundType, Left> = BoundaryOf::<Left>::into_ordered(BoundType::Inclusive);
+/// let right: SideInclusion<BoundType, Right> = BoundaryOf::<Right>::into_ordered(BoundType::Inclusive);
+/// assert!(left < BoundaryOf::<Left>::into_ordered(BoundType::Exclusive));
+/// assert!(right > BoundaryOf::<Right>::into_ordered(BoundType::Exclusive));
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct SideInclusion<B, S>(B, PhantomData<S>);
 
 mod ordering {
     use super::{Left, Right, SideInclusion};
-    use crate::{BoundType, Exclusive, Inclusive};
+    use crate::{BoundType, Exclusive, Inclusive, Unbounded};
 
     impl<B: PartialEq, S> PartialEq for SideInclusion<B, S> {
         fn eq(&self, other: &Self) -> bool {
@@ -37,12 +54,12 @@ mod ordering {
     macro_rules! impl_ord {
         (($lhs:ident, $rhs:ident): $type:ty => $body:expr) => {
             impl PartialOrd for $type {
-                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
                     Some(self.cmp(other))
                 }
             }
             impl Ord for $type {
-                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                     let $lhs = self;
                     let $rhs = other;
                     $body
@@ -51,21 +68,23 @@ mod ordering {
         };
     }
 
-    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Left> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Left> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Right> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Right> => std::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Left> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Left> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Right> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Right> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Unbounded, Left> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Unbounded, Right> => core::cmp::Ordering::Equal);
     impl_ord!((lhs, rhs): SideInclusion<BoundType, Left> => match (lhs.0, rhs.0) {
-        (BoundType::Inclusive, BoundType::Inclusive) => std::cmp::Ordering::Equal,
-        (BoundType::Inclusive, BoundType::Exclusive) => std::cmp::Ordering::Less,
-        (BoundType::Exclusive, BoundType::Inclusive) => std::cmp::Ordering::Greater,
-        (BoundType::Exclusive, BoundType::Exclusive) => std::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Inclusive) => core::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Exclusive) => core::cmp::Ordering::Less,
+        (BoundType::Exclusive, BoundType::Inclusive) => core::cmp::Ordering::Greater,
+        (BoundType::Exclusive, BoundType::Exclusive) => core::cmp::Ordering::Equal,
     });
     impl_ord!((lhs, rhs): SideInclusion<BoundType, Right> => match (lhs.0, rhs.0) {
-        (BoundType::Inclusive, BoundType::Inclusive) => std::cmp::Ordering::Equal,
-        (BoundType::Inclusive, BoundType::Exclusive) => std::cmp::Ordering::Greater,
-        (BoundType::Exclusive, BoundType::Inclusive) => std::cmp::Ordering::Less,
-        (BoundType::Exclusive, BoundType::Exclusive) => std::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Inclusive) => core::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Exclusive) => core::cmp::Ordering::Greater,
+        (BoundType::Exclusive, BoundType::Inclusive) => core::cmp::Ordering::Less,
+        (BoundType::Exclusive, BoundType::Exclusive) => core::cmp::Ordering::Equal,
     });
 }
 
@@ -120,11 +139,25 @@ impl Boundary for Inclusive {
     fn less<T: PartialOrd>(&self, this: &T, t: &T) -> bool {
         this <= t
     }
+    fn std_bound<'a, T>(&self, limit: &'a T) -> core::ops::Bound<&'a T> {
+        core::ops::Bound::Included(limit)
+    }
 }
 impl Boundary for Exclusive {
     fn less<T: PartialOrd>(&self, this: &T, t: &T) -> bool {
         this < t
     }
+    fn std_bound<'a, T>(&self, limit: &'a T) -> core::ops::Bound<&'a T> {
+        core::ops::Bound::Excluded(limit)
+    }
+}
+impl Boundary for Unbounded {
+    fn less<T: PartialOrd>(&self, _this: &T, _t: &T) -> bool {
+        true
+    }
+    fn std_bound<'a, T>(&self, _limit: &'a T) -> core::ops::Bound<&'a T> {
+        core::ops::Bound::Unbounded
+    }
 }
 impl Boundary for BoundType {
     fn less<T: PartialOrd>(&self, this: &T, t: &T) -> bool {
@@ -133,6 +166,12 @@ impl Boundary for BoundType {
             BoundType::Exclusive => this < t,
         }
     }
+    fn std_bound<'a, T>(&self, limit: &'a T) -> core::ops::Bound<&'a T> {
+        match self {
+            BoundType::Inclusive => core::ops::Bound::Included(limit),
+            BoundType::Exclusive => core::ops::Bound::Excluded(limit),
+        }
+    }
 }
 
 impl<LR> BoundaryOf<LR> for Inclusive
@@ -162,3 +201,12 @@ where
         SideInclusion(self, PhantomData)
     }
 }
+impl<LR> BoundaryOf<LR> for Unbounded
+where
+    SideInclusion<Self, LR>: Ord,
+{
+    type Ordered = SideInclusion<Self, LR>;
+    fn into_ordered(self) -> Self::Ordered {
+        SideInclusion(self, PhantomData)
+    }
+}
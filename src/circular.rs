@@ -0,0 +1,93 @@
+use crate::{Exclusive, Inclusive, Interval};
+
+/// Half-open interval *[start, end)* on a circular domain *[0, modulus)*, e.g., angles in degrees.
+/// If `start > end`, the interval wraps around, i.e., *[start, modulus) ∪ [0, end)*.
+/// ```
+/// use inter_val::{CircularInterval, Inclusive, Exclusive};
+/// let a = CircularInterval::new(350.0, 10.0, 360.0).unwrap();   // [350, 10)
+/// assert!(a.is_wrapping());
+/// assert!(a.contains(&355.0) && a.contains(&0.0) && a.contains(&5.0));
+/// assert!(!a.contains(&10.0) && !a.contains(&180.0));
+/// assert_eq!(a.measure(), 20.0);
+/// assert_eq!(
+///     a.pieces().collect::<Vec<_>>(),
+///     vec![&Inclusive.at(350.0).to(Exclusive.at(360.0)), &Inclusive.at(0.0).to(Exclusive.at(10.0))]
+/// );
+///
+/// let b = CircularInterval::new(5.0, 90.0, 360.0).unwrap();
+/// let c = CircularInterval::new(90.0, 350.0, 360.0).unwrap();
+/// assert!(a.overlaps(&b) && !a.overlaps(&c) && !b.overlaps(&c));
+///
+/// assert_eq!(CircularInterval::new(90.0, 90.0, 360.0).unwrap().measure(), 360.0);
+/// assert!(CircularInterval::new(360.0, 10.0, 360.0).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircularInterval<T> {
+    modulus: T,
+    first: Interval<T, Inclusive, Exclusive>,
+    second: Option<Interval<T, Inclusive, Exclusive>>,
+}
+
+impl<T: PartialOrd + Clone + num::Zero> CircularInterval<T> {
+    /// Returns `None` unless `0 <= start < modulus` and `0 <= end <= modulus`.
+    /// `start == end` gives the whole circle.
+    pub fn new(start: T, end: T, modulus: T) -> Option<Self> {
+        let domain = Interval::try_new(Inclusive.at(T::zero()), Inclusive.at(modulus.clone()))?;
+        if !domain.contains(&start) || start == modulus || !domain.contains(&end) {
+            return None;
+        }
+        if start < end {
+            Some(Self {
+                modulus,
+                first: Interval::try_new(Inclusive.at(start), Exclusive.at(end))?,
+                second: None,
+            })
+        } else {
+            Some(Self {
+                first: Interval::try_new(Inclusive.at(start), Exclusive.at(modulus.clone()))?,
+                second: Interval::try_new(Inclusive.at(T::zero()), Exclusive.at(end)),
+                modulus,
+            })
+        }
+    }
+
+    pub fn modulus(&self) -> &T {
+        &self.modulus
+    }
+
+    pub fn start(&self) -> &T {
+        self.first.inf()
+    }
+
+    pub fn end(&self) -> &T {
+        self.second.as_ref().unwrap_or(&self.first).sup()
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.start() >= self.end()
+    }
+
+    /// One or two non-wrapping pieces in *[0, modulus)*, starting from `start()`.
+    pub fn pieces(&self) -> impl Iterator<Item = &Interval<T, Inclusive, Exclusive>> {
+        core::iter::once(&self.first).chain(self.second.as_ref())
+    }
+
+    /// `t` is expected to be in *[0, modulus)*.
+    pub fn contains(&self, t: &T) -> bool {
+        self.pieces().any(|i| i.contains(t))
+    }
+
+    /// Both intervals are expected to have the same modulus.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.pieces().any(|i| other.pieces().any(|j| i.overlaps(j)))
+    }
+
+    pub fn measure(&self) -> T
+    where
+        T: core::ops::Sub<Output = T>,
+    {
+        self.pieces()
+            .map(|i| i.measure())
+            .fold(T::zero(), |a, b| a + b)
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{traits::IntoGeneral, Bound, BoundType, Exclusive, Inclusive, Interval};
+use crate::{
+    traits::IntoGeneral, Bound, BoundType, BoundTypeMismatch, Exclusive, Inclusive, Interval,
+};
 
 impl<T> From<T> for Bound<T, Inclusive> {
     fn from(t: T) -> Self {
@@ -17,6 +19,36 @@ impl<T> From<T> for Bound<T, Exclusive> {
     }
 }
 
+impl From<Inclusive> for BoundType {
+    fn from(_: Inclusive) -> Self {
+        BoundType::Inclusive
+    }
+}
+impl From<Exclusive> for BoundType {
+    fn from(_: Exclusive) -> Self {
+        BoundType::Exclusive
+    }
+}
+
+impl TryFrom<BoundType> for Inclusive {
+    type Error = BoundTypeMismatch;
+    fn try_from(b: BoundType) -> Result<Self, Self::Error> {
+        match b {
+            BoundType::Inclusive => Ok(Inclusive),
+            BoundType::Exclusive => Err(BoundTypeMismatch(b)),
+        }
+    }
+}
+impl TryFrom<BoundType> for Exclusive {
+    type Error = BoundTypeMismatch;
+    fn try_from(b: BoundType) -> Result<Self, Self::Error> {
+        match b {
+            BoundType::Exclusive => Ok(Exclusive),
+            BoundType::Inclusive => Err(BoundTypeMismatch(b)),
+        }
+    }
+}
+
 /// ```
 /// use inter_val::{BoundType, Inclusive, Interval};
 /// let src: Interval<i32, Inclusive> = Inclusive.at(0).to(Inclusive.at(10));
@@ -0,0 +1,175 @@
+use crate::{Exclusive, Inclusive, Interval};
+use alloc::{vec, vec::Vec};
+
+/// Set of integers within a fixed universe *[a, b)*, stored as a bit vector.
+/// Membership test is *O(1)* and bulk operations work on whole intervals.
+/// ```
+/// use inter_val::{DenseIntervalSet, Inclusive, Exclusive};
+/// let mut a = DenseIntervalSet::new(Inclusive.at(0).to(Exclusive.at(100)));
+/// a.insert(Inclusive.at(10).to(Exclusive.at(20)));
+/// a.insert(Inclusive.at(15).to(Exclusive.at(30)));
+/// a.insert(Inclusive.at(90).to(Exclusive.at(200))); // clipped to the universe
+/// assert!(a.contains(10) && a.contains(29) && !a.contains(30));
+/// assert_eq!(
+///     a.to_intervals(),
+///     vec![Inclusive.at(10).to(Exclusive.at(30)), Inclusive.at(90).to(Exclusive.at(100))]
+/// );
+///
+/// a.remove(Inclusive.at(12).to(Exclusive.at(14)));
+/// assert!(!a.contains(12) && a.contains(14));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseIntervalSet {
+    universe: Interval<i64, Inclusive, Exclusive>,
+    bits: Vec<u64>,
+}
+
+impl DenseIntervalSet {
+    /// Empty set over `universe`. Panics if the number of integers in `universe` overflows `i64` or `usize`.
+    /// ```should_panic
+    /// # use inter_val::{DenseIntervalSet, Inclusive, Exclusive};
+    /// DenseIntervalSet::new(Inclusive.at(i64::MIN).to(Exclusive.at(0)));
+    /// ```
+    pub fn new(universe: Interval<i64, Inclusive, Exclusive>) -> Self {
+        let len = universe
+            .sup()
+            .checked_sub(*universe.inf())
+            .and_then(|len| usize::try_from(len).ok())
+            .expect("the universe is too wide");
+        Self {
+            universe,
+            bits: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn universe(&self) -> &Interval<i64, Inclusive, Exclusive> {
+        &self.universe
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&w| w == 0)
+    }
+
+    pub fn contains(&self, t: i64) -> bool {
+        self.universe.contains(&t) && {
+            let i = self.index(t);
+            self.bits[i / 64] & (1 << (i % 64)) != 0
+        }
+    }
+
+    /// Add all integers in `i`. The part out of the universe is ignored.
+    /// ```
+    /// use inter_val::{DenseIntervalSet, Inclusive, Exclusive};
+    /// let mut a = DenseIntervalSet::new(Inclusive.at(0).to(Exclusive.at(130)));
+    /// assert!(a.is_empty() && a.to_intervals().is_empty());
+    ///
+    /// a.insert(Inclusive.at(63).to(Exclusive.at(64)));  // {63}, the last bit of a word
+    /// assert!(a.contains(63) && !a.contains(62) && !a.contains(64));
+    ///
+    /// a.insert(Inclusive.at(64).to(Exclusive.at(128)));  // adjacent, a whole word
+    /// assert_eq!(a.to_intervals(), vec![Inclusive.at(63).to(Exclusive.at(128))]);
+    ///
+    /// a.insert(Inclusive.at(129).to(Exclusive.at(200)));  // clipped to {129}
+    /// assert!(!a.contains(128) && a.contains(129) && !a.contains(130));
+    /// assert_eq!(a.to_intervals().len(), 2);
+    /// ```
+    pub fn insert(&mut self, i: Interval<i64, Inclusive, Exclusive>) {
+        self.fill(i, true)
+    }
+
+    /// Remove all integers in `i`.
+    pub fn remove(&mut self, i: Interval<i64, Inclusive, Exclusive>) {
+        self.fill(i, false)
+    }
+
+    /// Panics if the universes differ.
+    /// ```
+    /// use inter_val::{DenseIntervalSet, Inclusive, Exclusive};
+    /// let universe = Inclusive.at(0).to(Exclusive.at(10));
+    /// let mut a = DenseIntervalSet::new(universe);
+    /// let mut b = DenseIntervalSet::new(universe);
+    /// a.insert(Inclusive.at(0).to(Exclusive.at(5)));
+    /// b.insert(Inclusive.at(3).to(Exclusive.at(8)));
+    /// assert_eq!(a.union(&b).to_intervals(), vec![Inclusive.at(0).to(Exclusive.at(8))]);
+    /// assert_eq!(a.intersection(&b).to_intervals(), vec![Inclusive.at(3).to(Exclusive.at(5))]);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Panics if the universes differ.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Maximal runs of consecutive members in ascending order.
+    pub fn to_intervals(&self) -> Vec<Interval<i64, Inclusive, Exclusive>> {
+        let mut runs = Vec::new();
+        let mut i = 0;
+        while let Some(start) = self.next_bit(i, true) {
+            let end = self.next_bit(start, false).unwrap_or(self.len());
+            runs.push(
+                Inclusive
+                    .at(self.value(start))
+                    .to(Exclusive.at(self.value(end))),
+            );
+            i = end;
+        }
+        runs
+    }
+
+    fn len(&self) -> usize {
+        (self.universe.sup() - self.universe.inf()) as usize
+    }
+    fn index(&self, t: i64) -> usize {
+        (t - self.universe.inf()) as usize
+    }
+    fn value(&self, i: usize) -> i64 {
+        self.universe.inf() + i as i64
+    }
+
+    fn fill(&mut self, i: Interval<i64, Inclusive, Exclusive>, value: bool) {
+        let Some(i) = self.universe.intersection(&i) else {
+            return;
+        };
+        let (lo, hi) = (self.index(*i.inf()), self.index(*i.sup()));
+        for w in lo / 64..hi.div_ceil(64) {
+            let from = lo.max(w * 64) - w * 64;
+            let to = hi.min(w * 64 + 64) - w * 64;
+            let mask = (u64::MAX >> (64 - (to - from))) << from;
+            if value {
+                self.bits[w] |= mask;
+            } else {
+                self.bits[w] &= !mask;
+            }
+        }
+    }
+
+    /// Index of the first bit equal to `value` at or after `from`.
+    fn next_bit(&self, from: usize, value: bool) -> Option<usize> {
+        let flip = if value { 0 } else { u64::MAX };
+        let mut w = from / 64;
+        let mut word = (self.bits.get(w)? ^ flip) & (u64::MAX << (from % 64));
+        loop {
+            if word != 0 {
+                let i = w * 64 + word.trailing_zeros() as usize;
+                return (i < self.len()).then_some(i);
+            }
+            w += 1;
+            word = self.bits.get(w)? ^ flip;
+        }
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(self.universe, other.universe, "universes must be the same");
+        Self {
+            universe: self.universe,
+            bits: self
+                .bits
+                .iter()
+                .zip(other.bits.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        }
+    }
+}
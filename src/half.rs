@@ -1,22 +1,22 @@
 use crate::{
     bound_type::{Left, Right},
     traits::{BoundaryOf, Ceil, Flip, Floor, IntoGeneral},
-    Bound, Exclusive, Inclusive,
+    Bound, BoundType, Exclusive, Inclusive,
 };
 
 #[derive(Debug, Clone, Copy)]
-pub struct HalfBounded<T, B, LR>(pub(crate) Bound<T, B>, std::marker::PhantomData<LR>);
+pub struct HalfBounded<T, B, LR>(pub(crate) Bound<T, B>, core::marker::PhantomData<LR>);
 
 pub type LeftBounded<T, B> = HalfBounded<T, B, Left>;
 pub type RightBounded<T, B> = HalfBounded<T, B, Right>;
 
-impl<T, B, LR> std::ops::Deref for HalfBounded<T, B, LR> {
+impl<T, B, LR> core::ops::Deref for HalfBounded<T, B, LR> {
     type Target = Bound<T, B>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<T, B, LR> std::ops::DerefMut for HalfBounded<T, B, LR> {
+impl<T, B, LR> core::ops::DerefMut for HalfBounded<T, B, LR> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -39,36 +39,65 @@ mod ordering {
         }
     }
     impl<T: PartialOrd, B: BoundaryOf<LR>, LR> PartialOrd for HalfBounded<T, B, LR> {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
             self.ordering_key().partial_cmp(&other.ordering_key())
         }
     }
+    impl<T: Ord, B: BoundaryOf<LR> + Eq, LR> Ord for HalfBounded<T, B, LR> {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.ordering_key().cmp(&other.ordering_key())
+        }
+    }
 }
 
 impl<T, B, LR> From<Bound<T, B>> for HalfBounded<T, B, LR> {
     fn from(b: Bound<T, B>) -> Self {
-        HalfBounded(b, std::marker::PhantomData)
+        HalfBounded(b, core::marker::PhantomData)
     }
 }
 
 impl<T, B: IntoGeneral, LR> IntoGeneral for HalfBounded<T, B, LR> {
     type General = HalfBounded<T, B::General, LR>;
     fn into_general(self) -> Self::General {
-        HalfBounded(self.0.into_general(), std::marker::PhantomData)
+        HalfBounded(self.0.into_general(), core::marker::PhantomData)
     }
 }
 
 impl<T, B: Flip, LR: Flip> Flip for HalfBounded<T, B, LR> {
     type Flip = HalfBounded<T, B::Flip, LR::Flip>;
     fn flip(self) -> Self::Flip {
-        HalfBounded(self.0.flip(), std::marker::PhantomData)
+        HalfBounded(self.0.flip(), core::marker::PhantomData)
     }
 }
 
 impl<T, B, LR> HalfBounded<T, B, LR> {
+    pub(crate) const fn new(bound: Bound<T, B>) -> Self {
+        HalfBounded(bound, core::marker::PhantomData)
+    }
     pub fn cast<U: From<T>>(self) -> HalfBounded<U, B, LR> {
         self.0.cast().into()
     }
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> HalfBounded<U, B, LR> {
+        self.0.map(f).into()
+    }
+    pub fn as_ref(&self) -> HalfBounded<&T, B, LR>
+    where
+        B: Copy,
+    {
+        self.0.as_ref().into()
+    }
+}
+impl<T, B: Copy + Into<BoundType>, LR> HalfBounded<T, B, LR> {
+    /// Boundary type as a runtime value.
+    /// ```
+    /// use inter_val::{BoundType, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(1));
+    /// assert_eq!(a.left().bound_type(), BoundType::Inclusive);
+    /// assert_eq!(a.right().bound_type(), BoundType::Exclusive);
+    /// ```
+    pub fn bound_type(&self) -> BoundType {
+        self.0.bound_type.into()
+    }
 }
 impl<T: num::NumCast, B, LR> HalfBounded<T, B, LR> {
     pub fn try_cast<U: num::NumCast>(self) -> Option<HalfBounded<U, B, LR>> {
@@ -102,14 +131,54 @@ impl<T: PartialOrd, B: BoundaryOf<LR>, LR> HalfBounded<T, B, LR> {
 
 impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
     pub fn includes(&self, other: &Self) -> bool {
-        self.limit <= other.limit
+        self <= other
     }
     pub fn contains(&self, t: &T) -> bool {
         self.bound_type.less(&self.limit, t)
     }
+
+    /// Three-way comparison of the bound against `t`, where an exclusive bound lies just above its limit.
+    /// `t` is contained iff the result is not `Greater`.
+    /// ```
+    /// use inter_val::{LeftBounded, Inclusive, Exclusive};
+    /// use std::cmp::Ordering;
+    /// let a: LeftBounded<_, _> = Inclusive.at(4).into();
+    /// let b: LeftBounded<_, _> = Exclusive.at(4).into();
+    /// assert_eq!(a.partial_cmp_val(&4), Some(Ordering::Equal));
+    /// assert_eq!(b.partial_cmp_val(&4), Some(Ordering::Greater));
+    /// assert_eq!(b.partial_cmp_val(&3), Some(Ordering::Greater));
+    /// assert_eq!(b.partial_cmp_val(&5), Some(Ordering::Less));
+    /// ```
+    pub fn partial_cmp_val(&self, t: &T) -> Option<core::cmp::Ordering> {
+        match self.limit.partial_cmp(t)? {
+            core::cmp::Ordering::Equal if !self.contains(t) => Some(core::cmp::Ordering::Greater),
+            ordering => Some(ordering),
+        }
+    }
     pub fn intersection<'a>(&'a self, other: &'a Self) -> &'a Self {
         self.max(other)
     }
+
+    /// The more restrictive of the two bounds, i.e., by value version of `intersection()`.
+    /// At the same limit, the exclusive bound is tighter.
+    /// ```
+    /// use inter_val::{BoundType, LeftBounded};
+    /// let a: LeftBounded<_, _> = BoundType::Inclusive.at(4).into();
+    /// let b: LeftBounded<_, _> = BoundType::Exclusive.at(4).into();
+    /// let c: LeftBounded<_, _> = BoundType::Inclusive.at(5).into();
+    /// assert_eq!(a.tighter(b), b);
+    /// assert_eq!(a.looser(b), a);
+    /// assert_eq!(b.tighter(c), c);
+    /// assert_eq!(b.looser(c), b);
+    /// ```
+    pub fn tighter(self, other: Self) -> Self {
+        partial_max(self, other)
+    }
+
+    /// The less restrictive of the two bounds, i.e., by value version of `union()`.
+    pub fn looser(self, other: Self) -> Self {
+        partial_min(self, other)
+    }
     pub fn union<'a>(&'a self, other: &'a Self) -> &'a Self {
         self.min(other)
     }
@@ -124,7 +193,7 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
 
     pub fn dilate(self, delta: T) -> Self
     where
-        T: std::ops::Sub<Output = T>,
+        T: core::ops::Sub<Output = T>,
     {
         Bound {
             limit: self.0.limit - delta,
@@ -133,6 +202,22 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
         .into()
     }
 
+    pub fn checked_dilate(self, delta: T) -> Option<Self>
+    where
+        T: num::CheckedSub,
+    {
+        let limit = self.0.limit.checked_sub(&delta)?;
+        Some(self.0.map(|_| limit).into())
+    }
+
+    pub fn saturating_dilate(self, delta: T) -> Self
+    where
+        T: num::traits::SaturatingSub,
+    {
+        let limit = self.0.limit.saturating_sub(&delta);
+        self.0.map(|_| limit).into()
+    }
+
     pub fn inf(&self) -> &T {
         &self.limit
     }
@@ -172,14 +257,55 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
 
 impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
     pub fn includes(&self, other: &Self) -> bool {
-        other.limit <= self.limit
+        other <= self
     }
     pub fn contains(&self, t: &T) -> bool {
         self.bound_type.less(t, &self.limit)
     }
+
+    /// Three-way comparison of the bound against `t`, where an exclusive bound lies just below its limit.
+    /// `t` is contained iff the result is not `Less`.
+    /// ```
+    /// use inter_val::{RightBounded, Inclusive, Exclusive};
+    /// use std::cmp::Ordering;
+    /// let a: RightBounded<_, _> = Inclusive.at(4).into();
+    /// let b: RightBounded<_, _> = Exclusive.at(4).into();
+    /// assert_eq!(a.partial_cmp_val(&4), Some(Ordering::Equal));
+    /// assert_eq!(b.partial_cmp_val(&4), Some(Ordering::Less));
+    /// assert_eq!(b.partial_cmp_val(&3), Some(Ordering::Greater));
+    ///
+    /// let c: RightBounded<_, _> = Exclusive.at(1.0).into();
+    /// assert_eq!(c.partial_cmp_val(&f64::NAN), None);
+    /// ```
+    pub fn partial_cmp_val(&self, t: &T) -> Option<core::cmp::Ordering> {
+        match self.limit.partial_cmp(t)? {
+            core::cmp::Ordering::Equal if !self.contains(t) => Some(core::cmp::Ordering::Less),
+            ordering => Some(ordering),
+        }
+    }
     pub fn intersection<'a>(&'a self, other: &'a Self) -> &'a Self {
         self.min(other)
     }
+
+    /// The more restrictive of the two bounds, i.e., by value version of `intersection()`.
+    /// At the same limit, the exclusive bound is tighter.
+    /// ```
+    /// use inter_val::{BoundType, RightBounded};
+    /// let a: RightBounded<_, _> = BoundType::Inclusive.at(4).into();
+    /// let b: RightBounded<_, _> = BoundType::Exclusive.at(4).into();
+    /// let c: RightBounded<_, _> = BoundType::Inclusive.at(3).into();
+    /// assert_eq!(a.tighter(b), b);
+    /// assert_eq!(a.looser(b), a);
+    /// assert_eq!(b.tighter(c), c);
+    /// ```
+    pub fn tighter(self, other: Self) -> Self {
+        partial_min(self, other)
+    }
+
+    /// The less restrictive of the two bounds, i.e., by value version of `union()`.
+    pub fn looser(self, other: Self) -> Self {
+        partial_max(self, other)
+    }
     pub fn union<'a>(&'a self, other: &'a Self) -> &'a Self {
         self.max(other)
     }
@@ -194,7 +320,7 @@ impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
 
     pub fn dilate(self, delta: T) -> Self
     where
-        T: std::ops::Add<Output = T>,
+        T: core::ops::Add<Output = T>,
     {
         Bound {
             limit: self.0.limit + delta,
@@ -203,6 +329,22 @@ impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
         .into()
     }
 
+    pub fn checked_dilate(self, delta: T) -> Option<Self>
+    where
+        T: num::CheckedAdd,
+    {
+        let limit = self.0.limit.checked_add(&delta)?;
+        Some(self.0.map(|_| limit).into())
+    }
+
+    pub fn saturating_dilate(self, delta: T) -> Self
+    where
+        T: num::traits::SaturatingAdd,
+    {
+        let limit = self.0.limit.saturating_add(&delta);
+        self.0.map(|_| limit).into()
+    }
+
     pub fn sup(&self) -> &T {
         &self.limit
     }
@@ -0,0 +1,85 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{BoundType, Interval};
+use alloc::{vec, vec::Vec};
+
+/// Counts of samples in the equal-width bins of a float interval, which are given by `Interval::subdivide()`.
+/// The bins tile the domain exactly, so a sample at the upper end of a closed domain falls in the last bin.
+/// ```
+/// use inter_val::{BoundType, Histogram, Inclusive, Exclusive};
+/// let mut latency = Histogram::new(Inclusive.between(0.0, 100.0), 4).unwrap();
+/// latency.extend([3.0, 24.9, 25.0, 60.0, 100.0, 100.1, -1.0]);
+/// assert_eq!(latency.counts(), &[2, 1, 1, 1]);
+/// assert_eq!(latency.outliers(), 2);
+/// assert_eq!(latency.bins()[1], BoundType::Inclusive.at(25.0).to(BoundType::Exclusive.at(50.0)));
+/// assert_eq!(latency.bins()[3], BoundType::Inclusive.at(75.0).to(BoundType::Inclusive.at(100.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<T> {
+    bins: Vec<Interval<T, BoundType>>,
+    counts: Vec<usize>,
+    outliers: usize,
+}
+
+impl<T: num::Float> Histogram<T> {
+    /// Histogram of `n` empty bins over `domain`.
+    /// Returns `None` if `domain` cannot be subdivided into `n` non-empty bins. See `Interval::subdivide()`.
+    /// ```
+    /// use inter_val::{Histogram, Inclusive, Exclusive};
+    /// assert_eq!(Histogram::new(Inclusive.between(0.0, 1.0), 3).unwrap().bins().len(), 3);
+    /// assert!(Histogram::new(Inclusive.at(1e16).to(Exclusive.at(1e16 + 4.0)), 3).is_none());
+    /// ```
+    pub fn new<L, R>(domain: Interval<T, L, R>, n: usize) -> Option<Self>
+    where
+        L: BoundaryOf<Left> + Into<BoundType>,
+        R: BoundaryOf<Right> + Into<BoundType>,
+    {
+        let bins: Vec<_> = domain.subdivide(n)?.collect();
+        Some(Self {
+            counts: vec![0; n],
+            bins,
+            outliers: 0,
+        })
+    }
+
+    /// Count `t` in the bin containing it, found by binary search.
+    /// Returns `false` and counts `t` as an outlier if it is out of the domain.
+    pub fn add(&mut self, t: T) -> bool {
+        let i = self.bins.partition_point(|bin| !bin.right().contains(&t));
+        match self.bins.get(i).filter(|bin| bin.contains(&t)) {
+            Some(_) => {
+                self.counts[i] += 1;
+                true
+            }
+            None => {
+                self.outliers += 1;
+                false
+            }
+        }
+    }
+
+    pub fn bins(&self) -> &[Interval<T, BoundType>] {
+        &self.bins
+    }
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Number of samples out of the domain.
+    pub fn outliers(&self) -> usize {
+        self.outliers
+    }
+
+    /// Iterate over the bins and their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T, BoundType>, usize)> {
+        self.bins.iter().zip(self.counts.iter().copied())
+    }
+}
+
+impl<T: num::Float> Extend<T> for Histogram<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for t in iter {
+            self.add(t);
+        }
+    }
+}
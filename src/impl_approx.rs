@@ -0,0 +1,64 @@
+use crate::Interval;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// Boundary types must be equal, and `epsilon` is applied to both boundaries.
+/// ```
+/// use inter_val::{Inclusive, Exclusive};
+/// use approx::assert_abs_diff_eq;
+/// let a = Inclusive.at(0.1 + 0.2).to(Exclusive.at(1.0));
+/// let b = Inclusive.at(0.3).to(Exclusive.at(1.0));
+/// assert_ne!(a, b);
+/// assert_abs_diff_eq!(a, b);
+/// assert_abs_diff_eq!(a, Inclusive.at(0.31).to(Exclusive.at(0.99)), epsilon = 0.02);
+/// ```
+impl<T: AbsDiffEq, L: PartialEq, R: PartialEq> AbsDiffEq for Interval<T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.left.bound_type == other.left.bound_type
+            && self.right.bound_type == other.right.bound_type
+            && self
+                .left
+                .limit
+                .abs_diff_eq(&other.left.limit, epsilon.clone())
+            && self.right.limit.abs_diff_eq(&other.right.limit, epsilon)
+    }
+}
+
+/// ```
+/// use inter_val::{Inclusive, Exclusive};
+/// use approx::{assert_relative_eq, assert_relative_ne};
+/// let a = Inclusive.at(1.0e9).to(Exclusive.at(2.0e9));
+/// assert_relative_eq!(a, Inclusive.at(1.0e9 + 1.0).to(Exclusive.at(2.0e9)), epsilon = 1e-9, max_relative = 1e-6);
+/// assert_relative_ne!(a, Inclusive.at(1.1e9).to(Exclusive.at(2.0e9)), max_relative = 1e-6);
+/// ```
+impl<T: RelativeEq, L: PartialEq, R: PartialEq> RelativeEq for Interval<T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.left.bound_type == other.left.bound_type
+            && self.right.bound_type == other.right.bound_type
+            && self
+                .left
+                .limit
+                .relative_eq(&other.left.limit, epsilon.clone(), max_relative.clone())
+            && self
+                .right
+                .limit
+                .relative_eq(&other.right.limit, epsilon, max_relative)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::Interval;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
+
+impl<Tz: TimeZone, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<DateTime<Tz>, L, R> {
+    /// Length of the interval as `chrono::TimeDelta`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    /// let a = Inclusive
+    ///     .at(Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap())
+    ///     .to(Exclusive.at(Utc.with_ymd_and_hms(2024, 1, 1, 17, 30, 0).unwrap()));
+    /// assert_eq!(a.duration(), TimeDelta::minutes(510));
+    /// ```
+    pub fn duration(&self) -> TimeDelta {
+        self.sup().clone() - self.inf().clone()
+    }
+
+    /// Check if the current time is in the interval.
+    /// ```
+    /// use inter_val::Inclusive;
+    /// use chrono::{TimeDelta, Utc};
+    /// let now = Utc::now();
+    /// assert!(Inclusive.between(now - TimeDelta::hours(1), now + TimeDelta::hours(1)).contains_now());
+    /// assert!(!Inclusive.between(now - TimeDelta::hours(2), now - TimeDelta::hours(1)).contains_now());
+    /// ```
+    pub fn contains_now(&self) -> bool {
+        self.contains(&Utc::now().with_timezone(&self.inf().timezone()))
+    }
+}
+
+impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<NaiveDateTime, L, R> {
+    /// Length of the interval as `chrono::TimeDelta`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// let day = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let a = Inclusive
+    ///     .at(day.and_hms_opt(9, 0, 0).unwrap())
+    ///     .to(Exclusive.at(day.and_hms_opt(17, 30, 0).unwrap()));
+    /// assert_eq!(a.duration(), TimeDelta::minutes(510));
+    /// ```
+    pub fn duration(&self) -> TimeDelta {
+        self.sup().signed_duration_since(*self.inf())
+    }
+
+    /// Check if the current local time is in the interval.
+    /// ```
+    /// use inter_val::Inclusive;
+    /// use chrono::{Local, TimeDelta};
+    /// let now = Local::now().naive_local();
+    /// assert!(Inclusive.between(now - TimeDelta::hours(1), now + TimeDelta::hours(1)).contains_now());
+    /// ```
+    pub fn contains_now(&self) -> bool {
+        self.contains(&Local::now().naive_local())
+    }
+}
+
+impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<NaiveDate, L, R> {
+    /// Length of the interval as `chrono::TimeDelta`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// let a = Inclusive
+    ///     .at(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())
+    ///     .to(Exclusive.at(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()));
+    /// assert_eq!(a.duration(), TimeDelta::days(29));
+    /// assert_eq!(a.measure(), a.duration());
+    /// ```
+    pub fn duration(&self) -> TimeDelta {
+        self.sup().signed_duration_since(*self.inf())
+    }
+
+    /// Check if today's local date is in the interval.
+    /// ```
+    /// use inter_val::Inclusive;
+    /// use chrono::{Local, TimeDelta};
+    /// let today = Local::now().date_naive();
+    /// assert!(Inclusive.between(today, today + TimeDelta::days(7)).contains_now());
+    /// assert!(!Inclusive.between(today + TimeDelta::days(1), today + TimeDelta::days(7)).contains_now());
+    /// ```
+    pub fn contains_now(&self) -> bool {
+        self.contains(&Local::now().date_naive())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    bound_type::{Left, Right},
+    traits::BoundaryOf,
+    Bound, BoundType, Exclusive, Inclusive, Interval,
+};
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    strategy::{FilterMap, Just, Strategy, Union},
+};
+
+impl Arbitrary for Inclusive {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Inclusive)
+    }
+}
+
+impl Arbitrary for Exclusive {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Exclusive)
+    }
+}
+
+impl Arbitrary for BoundType {
+    type Parameters = ();
+    type Strategy = Union<Just<Self>>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Union::new([Just(BoundType::Inclusive), Just(BoundType::Exclusive)])
+    }
+}
+
+type IntervalStrategy<T, L, R> = FilterMap<
+    (
+        <T as Arbitrary>::Strategy,
+        <T as Arbitrary>::Strategy,
+        <L as Arbitrary>::Strategy,
+        <R as Arbitrary>::Strategy,
+    ),
+    fn((T, T, L, R)) -> Option<Interval<T, L, R>>,
+>;
+
+/// Generates valid intervals only, i.e., the left boundary is less than the right one,
+/// and `NaN` is never used as a boundary.
+/// ```
+/// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+/// use proptest::{prelude::*, test_runner::TestRunner};
+/// let mut runner = TestRunner::default();
+/// runner.run(&any::<Interval<i64, Inclusive, Exclusive>>(), |a| {
+///     prop_assert!(a.inf() < a.sup());
+///     Ok(())
+/// }).unwrap();
+/// runner.run(&any::<Interval<f64, BoundType>>(), |a| {
+///     prop_assert!(a.inf() <= a.sup());
+///     prop_assert!(a.measure() >= 0.0);
+///     Ok(())
+/// }).unwrap();
+/// ```
+impl<T, L, R> Arbitrary for Interval<T, L, R>
+where
+    T: Arbitrary + PartialOrd,
+    L: Arbitrary + BoundaryOf<Left>,
+    R: Arbitrary + BoundaryOf<Right>,
+{
+    type Parameters = ();
+    type Strategy = IntervalStrategy<T, L, R>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<T>(), any::<T>(), any::<L>(), any::<R>()).prop_filter_map(
+            "empty interval",
+            |(a, b, l, r)| {
+                let (a, b) = if b < a { (b, a) } else { (a, b) };
+                Interval::try_new(
+                    Bound {
+                        limit: a,
+                        bound_type: l,
+                    },
+                    Bound {
+                        limit: b,
+                        bound_type: r,
+                    },
+                )
+            },
+        )
+    }
+}
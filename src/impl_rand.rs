@@ -0,0 +1,129 @@
+use crate::{
+    bound_type::{Left, Right},
+    traits::{BoundaryOf, Ceil, Floor},
+    Bound, Interval,
+};
+use rand::{distributions::uniform::SampleRange, Rng, RngCore};
+
+macro_rules! impl_sample_for_integer {
+    ($T:ty) => {
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<$T, L, R>
+        where
+            Bound<$T, L>: Ceil<$T>,
+            Bound<$T, R>: Floor<$T>,
+        {
+            /// Draw an integer in `self` uniformly. Panics if `self` contains no integer, e.g., *(0, 1)*.
+            /// ```
+            /// use inter_val::{Inclusive, Exclusive};
+            /// use rand::SeedableRng;
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            /// let a = Exclusive.at(0_i32).to(Inclusive.at(3)); // (0, 3]
+            /// for _ in 0..100 {
+            ///     let t = a.sample(&mut rng);
+            ///     assert!(1 <= t && t <= 3);
+            /// }
+            /// ```
+            pub fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> $T {
+                rng.gen_range(self.left().ceil()..=self.right().floor())
+            }
+        }
+
+        /// Enables `rng.gen_range(interval)`.
+        /// ```
+        /// use inter_val::{Inclusive, Exclusive};
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        /// let t: i32 = rng.gen_range(Inclusive.at(-3).to(Exclusive.at(3)));
+        /// assert!(-3 <= t && t < 3);
+        /// let u: u8 = rng.gen_range(Exclusive.at(250).to(Inclusive.at(255)));
+        /// assert!(250 < u);
+        /// ```
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> SampleRange<$T> for Interval<$T, L, R>
+        where
+            Bound<$T, L>: Ceil<$T>,
+            Bound<$T, R>: Floor<$T>,
+        {
+            fn sample_single<G: RngCore + ?Sized>(self, rng: &mut G) -> $T {
+                self.sample(rng)
+            }
+            fn is_empty(&self) -> bool {
+                self.left().ceil() > self.right().floor()
+            }
+        }
+    };
+}
+impl_sample_for_integer!(i8);
+impl_sample_for_integer!(i16);
+impl_sample_for_integer!(i32);
+impl_sample_for_integer!(i64);
+impl_sample_for_integer!(i128);
+impl_sample_for_integer!(isize);
+impl_sample_for_integer!(u8);
+impl_sample_for_integer!(u16);
+impl_sample_for_integer!(u32);
+impl_sample_for_integer!(u64);
+impl_sample_for_integer!(u128);
+impl_sample_for_integer!(usize);
+
+macro_rules! impl_sample_for_float {
+    ($T:ty) => {
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<$T, L, R> {
+            /// Draw a point in `self` uniformly. Excluded boundaries are never drawn.
+            /// Panics if `self` is not finite or contains no float, e.g., *(1, 1 + ε)*.
+            /// ```
+            /// use inter_val::{Inclusive, Exclusive};
+            /// use rand::SeedableRng;
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            /// let a = Exclusive.at(0.0_f64).to(Exclusive.at(1.0)); // (0, 1)
+            /// for _ in 0..100 {
+            ///     assert!(a.contains(&a.sample(&mut rng)));
+            /// }
+            /// let b = Exclusive.at(1.0_f64).to(Inclusive.at(1.0 + f64::EPSILON)); // (1, 1 + ε]
+            /// assert_eq!(b.sample(&mut rng), 1.0 + f64::EPSILON);
+            /// ```
+            pub fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> $T {
+                let (lo, hi) = self.closed_limits();
+                rng.gen_range(lo..=hi)
+            }
+
+            /// The least and the greatest floats in `self`. `lo > hi` if `self` contains no float.
+            fn closed_limits(&self) -> ($T, $T) {
+                let lo = if self.is_left_closed() {
+                    *self.inf()
+                } else {
+                    self.inf().next_up()
+                };
+                let hi = if self.is_right_closed() {
+                    *self.sup()
+                } else {
+                    self.sup().next_down()
+                };
+                (lo, hi)
+            }
+        }
+
+        /// Enables `rng.gen_range(interval)`.
+        /// ```
+        /// use inter_val::{Inclusive, Exclusive};
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        /// let a = Inclusive.at(1.0).to(Exclusive.at(2.0));
+        /// let t: f64 = rng.gen_range(a);
+        /// assert!(a.contains(&t));
+        ///
+        /// let b = Exclusive.at(1.0).to(Exclusive.at(1.0 + f64::EPSILON)); // no float in between
+        /// assert!(rand::distributions::uniform::SampleRange::is_empty(&b));
+        /// ```
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> SampleRange<$T> for Interval<$T, L, R> {
+            fn sample_single<G: RngCore + ?Sized>(self, rng: &mut G) -> $T {
+                self.sample(rng)
+            }
+            fn is_empty(&self) -> bool {
+                let (lo, hi) = self.closed_limits();
+                lo > hi
+            }
+        }
+    };
+}
+impl_sample_for_float!(f32);
+impl_sample_for_float!(f64);
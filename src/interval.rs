@@ -1,6 +1,9 @@
 use crate::bound_type::{Left, Right};
-use crate::traits::{BoundaryOf, Ceil, Flip, Floor, IntoGeneral};
-use crate::{Bound, Exclusive, Inclusive, LeftBounded, RightBounded};
+use crate::traits::{Boundary, BoundaryOf, Ceil, Flip, Floor, IntoGeneral};
+use crate::{
+    Bound, BoundType, Exclusive, FloatIsNan, Inclusive, IntervalIsEmpty, LeftBounded, Nullable,
+    RightBounded, TryMapError,
+};
 
 /// Return type of `Interval::union()`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,15 +12,17 @@ pub struct IntervalUnion<T, L: Flip, R: Flip> {
     pub gap: Option<Interval<T, R::Flip, L::Flip>>,
 }
 impl<T, L: Flip, R: Flip> IntervalUnion<T, L, R> {
-    pub fn into_vec(self) -> Vec<Interval<T, L, R>> {
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> alloc::vec::Vec<Interval<T, L, R>> {
         self.into_iter().collect()
     }
 }
 impl<T, L: Flip, R: Flip> IntoIterator for IntervalUnion<T, L, R> {
     type Item = Interval<T, L, R>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter =
+        core::iter::Chain<core::option::IntoIter<Self::Item>, core::option::IntoIter<Self::Item>>;
     fn into_iter(self) -> Self::IntoIter {
-        if let Some(gap) = self.gap {
+        let (first, second) = if let Some(gap) = self.gap {
             let first = Interval {
                 left: self.span.left,
                 right: gap.left.flip(),
@@ -26,10 +31,11 @@ impl<T, L: Flip, R: Flip> IntoIterator for IntervalUnion<T, L, R> {
                 left: gap.right.flip(),
                 right: self.span.right,
             };
-            vec![first, second].into_iter()
+            (first, Some(second))
         } else {
-            vec![self.span].into_iter()
-        }
+            (self.span, None)
+        };
+        Some(first).into_iter().chain(second)
     }
 }
 
@@ -40,26 +46,58 @@ pub struct IntervalDifference<T, L: Flip, R: Flip> {
     pub upper: Option<Interval<T, R::Flip, R>>,
 }
 impl<T, L: Flip<Flip = R>, R: Flip<Flip = L>> IntervalDifference<T, L, R> {
-    pub fn into_vec(self) -> Vec<Interval<T, L, R>> {
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> alloc::vec::Vec<Interval<T, L, R>> {
         self.into_iter().collect()
     }
 }
 impl<T, L: Flip<Flip = R>, R: Flip<Flip = L>> IntoIterator for IntervalDifference<T, L, R> {
     type Item = Interval<T, L, R>;
     type IntoIter =
-        std::iter::Chain<std::option::IntoIter<Self::Item>, std::option::IntoIter<Self::Item>>;
+        core::iter::Chain<core::option::IntoIter<Self::Item>, core::option::IntoIter<Self::Item>>;
     fn into_iter(self) -> Self::IntoIter {
         self.lower.into_iter().chain(self.upper)
     }
 }
 
-fn is_valid_interval<T, L, R>(left: &LeftBounded<T, L>, right: &RightBounded<T, R>) -> bool
+/// Return type of `Interval::split_at()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalSplit<T, L, R> {
+    pub lower: Option<Interval<T, L, BoundType>>,
+    pub upper: Option<Interval<T, BoundType, R>>,
+}
+
+/// Operand which supplied a boundary of `Interval::intersection_tagged()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Which {
+    /// `self`
+    Lhs,
+    /// `other`
+    Rhs,
+    /// Both operands have the same boundary.
+    Tie,
+}
+
+pub(crate) fn is_valid_interval<T, L, R>(
+    left: &LeftBounded<T, L>,
+    right: &RightBounded<T, R>,
+) -> bool
 where
     T: PartialOrd,
     L: BoundaryOf<Left>,
     R: BoundaryOf<Right>,
 {
-    left.contains(&right.limit) && right.contains(&left.limit)
+    // An `Unbounded` side is never empty, but the limit of the other side must still be comparable, i.e., not NaN.
+    let is_unbounded = |b| matches!(b, core::ops::Bound::Unbounded);
+    match (
+        is_unbounded(left.bound_type.std_bound(&left.limit)),
+        is_unbounded(right.bound_type.std_bound(&right.limit)),
+    ) {
+        (false, false) => left.contains(&right.limit) && right.contains(&left.limit),
+        (true, false) => right.limit.partial_cmp(&right.limit).is_some(),
+        (false, true) => left.limit.partial_cmp(&left.limit).is_some(),
+        (true, true) => true,
+    }
 }
 
 /// Interval like *[a, b]*, *(a, b)*, *[a, b)*, and *(a, b]* for any `PartialOrd` type.
@@ -72,6 +110,7 @@ where
 /// * `Interval<T, Inclusive, Exclusive>` represents a right half-open interval, i.e., *[a, b)*.
 /// * `Interval<T, Exclusive, Inclusive>` represents a left half-open interval, i.e., *(a, b]*.
 /// * `Interval<T, BoundType>` represents any of the above.
+/// * `Interval<T, Unbounded, R>` and `Interval<T, L, Unbounded>` represent half-lines, e.g., *(-∞, b]* and *[a, +∞)*. See [`Unbounded`](crate::Unbounded).
 ///
 /// This type is considered as an interval on ℝ (real number line), even if an integer type is specified for `T`.
 ///
@@ -115,6 +154,53 @@ pub struct Interval<T, L = Inclusive, R = L> {
     pub(crate) right: RightBounded<T, R>,
 }
 
+/// Wrapper of [`Interval`] ordered lexicographically by the left boundary and then the right boundary.
+/// This is an arbitrary but total order for sorting or `BTreeSet`, not the inclusion order,
+/// so `Interval` itself does not implement `Ord`. See `partial_cmp_by_inclusion()` for the latter.
+/// ```
+/// use inter_val::{BoundType, Interval, Inclusive, Exclusive, Lexicographic};
+/// use std::collections::BTreeSet;
+/// let set: BTreeSet<_> = [
+///     Inclusive.at(3).to(Exclusive.at(5)),
+///     Inclusive.at(0).to(Exclusive.at(8)),
+///     Inclusive.at(0).to(Exclusive.at(2)),
+/// ]
+/// .into_iter()
+/// .map(Lexicographic)
+/// .collect();
+/// assert_eq!(set.into_iter().map(|x| x.0).collect::<Vec<_>>(), vec![
+///     Inclusive.at(0).to(Exclusive.at(2)),
+///     Inclusive.at(0).to(Exclusive.at(8)),
+///     Inclusive.at(3).to(Exclusive.at(5)),
+/// ]);
+///
+/// // [0, 1] < (0, 1) since an exclusive left boundary comes after the inclusive one.
+/// let a: Interval<i32, BoundType> = Inclusive.between(0, 1).into();
+/// let b: Interval<i32, BoundType> = Exclusive.between(0, 1).into();
+/// assert!(Lexicographic(a) < Lexicographic(b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lexicographic<T, L = Inclusive, R = L>(pub Interval<T, L, R>);
+
+impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> PartialOrd
+    for Lexicographic<T, L, R>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        (&self.0.left, &self.0.right).partial_cmp(&(&other.0.left, &other.0.right))
+    }
+}
+impl<T: Ord, L: BoundaryOf<Left> + Eq, R: BoundaryOf<Right> + Eq> Ord for Lexicographic<T, L, R> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (&self.0.left, &self.0.right).cmp(&(&other.0.left, &other.0.right))
+    }
+}
+
+impl<T, L, R> From<Interval<T, L, R>> for Lexicographic<T, L, R> {
+    fn from(i: Interval<T, L, R>) -> Self {
+        Self(i)
+    }
+}
+
 impl<T, L, R> Interval<T, L, R> {
     pub fn left(&self) -> &LeftBounded<T, L> {
         &self.left
@@ -122,7 +208,205 @@ impl<T, L, R> Interval<T, L, R> {
     pub fn right(&self) -> &RightBounded<T, R> {
         &self.right
     }
+
+    /// Boundary types of both sides as runtime values.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a: Interval<i32, BoundType> = Inclusive.at(0).to(Exclusive.at(1)).into();
+    /// assert_eq!(a.kind(), (BoundType::Inclusive, BoundType::Exclusive));
+    /// ```
+    pub fn kind(&self) -> (BoundType, BoundType)
+    where
+        L: Copy + Into<BoundType>,
+        R: Copy + Into<BoundType>,
+    {
+        (self.left.bound_type(), self.right.bound_type())
+    }
+
+    /// Boundaries as a pair of `std::ops::Bound`, e.g., to be passed to `BTreeMap::range()`.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive, Unbounded};
+    /// use std::collections::BTreeMap;
+    /// use std::ops::Bound;
+    /// let a: Interval<i32, BoundType> = Exclusive.at(1).to(Inclusive.at(3)).into();
+    /// assert_eq!(a.bounds(), (Bound::Excluded(&1), Bound::Included(&3)));
+    ///
+    /// let map: BTreeMap<_, _> = [(1, "a"), (2, "b"), (3, "c"), (4, "d")].into_iter().collect();
+    /// let values: Vec<_> = map.range(a.bounds()).map(|(_, v)| *v).collect();
+    /// assert_eq!(values, vec!["b", "c"]);
+    ///
+    /// let b = Inclusive.at(2.0).to(Unbounded::infinity());
+    /// assert_eq!(b.bounds(), (Bound::Included(&2.0), Bound::Unbounded));
+    /// ```
+    pub fn bounds(&self) -> (core::ops::Bound<&T>, core::ops::Bound<&T>)
+    where
+        L: Boundary,
+        R: Boundary,
+    {
+        (
+            self.left.bound_type.std_bound(&self.left.limit),
+            self.right.bound_type.std_bound(&self.right.limit),
+        )
+    }
+
+    /// Owned key which compares in the same way as [`Lexicographic`], i.e., by the left boundary and then by the right one.
+    /// The boundary types are given as [`SideInclusion`](crate::SideInclusion) rather than `BoundType`,
+    /// since an exclusive left boundary comes after the inclusive one at the same limit,
+    /// but an exclusive right boundary comes before the inclusive one.
+    /// For `Interval<T, BoundType>`, the key is `(T, SideInclusion<BoundType, Left>, T, SideInclusion<BoundType, Right>)`.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive, Lexicographic};
+    /// let mut items: Vec<Interval<i32, BoundType>> = vec![
+    ///     Exclusive.at(0).to(Inclusive.at(3)).into(),
+    ///     Inclusive.at(0).to(Inclusive.at(3)).into(),
+    ///     Inclusive.at(0).to(Exclusive.at(3)).into(),
+    /// ];
+    /// items.sort_by_key(|i| i.sort_key());
+    /// assert_eq!(items, vec![
+    ///     Inclusive.at(0).to(Exclusive.at(3)).into(),
+    ///     Inclusive.at(0).to(Inclusive.at(3)).into(),
+    ///     Exclusive.at(0).to(Inclusive.at(3)).into(),
+    /// ]);
+    /// assert!(items.windows(2).all(|w| Lexicographic(w[0]) <= Lexicographic(w[1])));
+    ///
+    /// use inter_val::{Left, Right, SideInclusion};
+    /// let key: (i32, SideInclusion<BoundType, Left>, i32, SideInclusion<BoundType, Right>) = items[0].sort_key();
+    /// assert!(key < items[1].sort_key());
+    ///
+    /// // Float keys can be compared by `partial_cmp`.
+    /// let mut items = vec![Inclusive.between(1.5, 2.0), Inclusive.between(0.5, 3.0)];
+    /// items.sort_by(|a, b| a.sort_key().partial_cmp(&b.sort_key()).unwrap());
+    /// assert_eq!(items[0].inf(), &0.5);
+    /// ```
+    pub fn sort_key(&self) -> (T, L::Ordered, T, R::Ordered)
+    where
+        T: Clone,
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        (
+            self.left.limit.clone(),
+            self.left.bound_type.into_ordered(),
+            self.right.limit.clone(),
+            self.right.bound_type.into_ordered(),
+        )
+    }
+
+    /// Check if the left boundary is inclusive. `false` for `Unbounded`.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive, Unbounded};
+    /// let a = Inclusive.at(0).to(Exclusive.at(1));
+    /// assert!(a.is_left_closed() && !a.is_right_closed());
+    /// let b: Interval<i32, BoundType> = a.into();
+    /// assert!(b.is_left_closed() && !b.is_right_closed());
+    /// assert!(!Unbounded::neg_infinity().to(Inclusive.at(0.0)).is_left_closed());
+    /// ```
+    pub fn is_left_closed(&self) -> bool
+    where
+        L: Boundary,
+    {
+        matches!(
+            self.left.bound_type.std_bound(&self.left.limit),
+            core::ops::Bound::Included(_)
+        )
+    }
+
+    /// Check if the right boundary is inclusive. `false` for `Unbounded`.
+    pub fn is_right_closed(&self) -> bool
+    where
+        R: Boundary,
+    {
+        matches!(
+            self.right.bound_type.std_bound(&self.right.limit),
+            core::ops::Bound::Included(_)
+        )
+    }
+
+    /// Create a new interval without checking that it is non-empty. Usable in `const` context.
+    /// The caller is responsible for the validity; methods may behave unexpectedly on an empty interval.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, Bound};
+    /// const A: Interval<u8, Inclusive, Exclusive> = Interval::new_unchecked(
+    ///     Bound { limit: 0, bound_type: Inclusive },
+    ///     Bound { limit: 10, bound_type: Exclusive },
+    /// );
+    /// assert_eq!(A, Inclusive.at(0).to(Exclusive.at(10)));
+    /// ```
+    pub const fn new_unchecked(left: Bound<T, L>, right: Bound<T, R>) -> Self {
+        Self {
+            left: LeftBounded::new(left),
+            right: RightBounded::new(right),
+        }
+    }
+}
+
+macro_rules! impl_new_const {
+    ($($T:ty),*) => {
+        $(
+            impl Interval<$T, Inclusive> {
+                /// Closed interval *[a, b]* usable in `const` context. Panics if `a > b`,
+                /// which is a compile error in `const` context.
+                /// ```
+                /// use inter_val::{Interval, Inclusive};
+                #[doc = concat!("const A: Interval<", stringify!($T), "> = Interval::<", stringify!($T), ">::new_const(1, 5);")]
+                /// assert_eq!(A, Inclusive.between(1, 5));
+                /// ```
+                pub const fn new_const(a: $T, b: $T) -> Self {
+                    assert!(a <= b, "left boundary must be less than or equal to right boundary");
+                    Self::new_unchecked(
+                        Bound { limit: a, bound_type: Inclusive },
+                        Bound { limit: b, bound_type: Inclusive },
+                    )
+                }
+            }
+        )*
+    };
+}
+impl_new_const!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_measure_int {
+    ($($T:ty),*) => {
+        $(
+            impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<$T, L, R> {
+                /// Same as `measure()` but returns `None` on overflow.
+                /// ```
+                /// use inter_val::Inclusive;
+                #[doc = concat!("let a = Inclusive.between(1", stringify!($T), ", 5);")]
+                /// assert_eq!(a.checked_measure(), Some(4));
+                ///
+                #[doc = concat!("let a = Inclusive.between(", stringify!($T), "::MIN, ", stringify!($T), "::MAX);")]
+                #[doc = concat!("assert_eq!(a.checked_measure().is_none(), ", stringify!($T), "::MIN != 0);")]
+                /// ```
+                pub fn checked_measure(&self) -> Option<$T> {
+                    self.sup().checked_sub(*self.inf())
+                }
+            }
+        )*
+    };
+}
+impl_checked_measure_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_measure_float {
+    ($($T:ty),*) => {
+        $(
+            impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<$T, L, R> {
+                /// Same as `measure()` but returns `None` if the result is not finite.
+                /// ```
+                /// use inter_val::Inclusive;
+                #[doc = concat!("let a = Inclusive.between(1.0", stringify!($T), ", 5.0);")]
+                /// assert_eq!(a.checked_measure(), Some(4.0));
+                #[doc = concat!("assert!(Inclusive.between(0.0, ", stringify!($T), "::INFINITY).checked_measure().is_none());")]
+                #[doc = concat!("assert!(Inclusive.between(", stringify!($T), "::MIN, ", stringify!($T), "::MAX).checked_measure().is_none());")]
+                /// ```
+                pub fn checked_measure(&self) -> Option<$T> {
+                    let measure = self.sup() - self.inf();
+                    measure.is_finite().then_some(measure)
+                }
+            }
+        )*
+    };
 }
+impl_checked_measure_float!(f32, f64);
 impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R> {
     fn new_(left: LeftBounded<T, L>, right: RightBounded<T, R>) -> Option<Self> {
         is_valid_interval(&left, &right).then_some(Self { left, right })
@@ -153,6 +437,23 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         Self::new_(left.into(), right.into())
     }
 
+    /// Same as `try_new()` but returns the empty interval as `Nullable::NULL`,
+    /// so that an empty result of set operations can be told apart from invalid input.
+    /// The empty interval contains no point.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, Nullable};
+    /// let a = Interval::new_allowing_empty(Inclusive.at(3), Exclusive.at(3)); // [3, 3)
+    /// assert!(a.is_null());
+    /// assert!(!a.contains(&3));
+    ///
+    /// let b = Interval::new_allowing_empty(Inclusive.at(0), Exclusive.at(3)); // [0, 3)
+    /// assert!(b.contains(&2));
+    /// assert_eq!(b.intersection(&a), Nullable::NULL);
+    /// ```
+    pub fn new_allowing_empty(left: Bound<T, L>, right: Bound<T, R>) -> Nullable<Self> {
+        Self::try_new(left, right).into()
+    }
+
     /// Create a new interval. Panics if the interval is empty.
     /// ```
     /// use std::any::{Any, TypeId};
@@ -227,7 +528,30 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         Self::try_between(a, b).unwrap()
     }
 
-    /// Shorthand of `.left().limit`
+    /// Replace the left boundary. Returns `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(5));
+    /// assert_eq!(a.with_left(Inclusive.at(2)), Some(Inclusive.at(2).to(Exclusive.at(5))));
+    /// assert_eq!(a.with_left(Inclusive.at(5)), None);
+    /// ```
+    pub fn with_left(self, left: Bound<T, L>) -> Option<Self> {
+        Self::new_(left.into(), self.right)
+    }
+
+    /// Replace the right boundary. Returns `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(5));
+    /// assert_eq!(a.with_right(Exclusive.at(9)), Some(Inclusive.at(0).to(Exclusive.at(9))));
+    /// assert_eq!(a.with_right(Exclusive.at(0)), None);
+    /// ```
+    pub fn with_right(self, right: Bound<T, R>) -> Option<Self> {
+        Self::new_(self.left, right.into())
+    }
+
+    /// Value of the left boundary, i.e., shorthand of `.left().limit`.
+    /// Prefer this to reaching the `Bound` fields through `Deref`.
     /// ```
     /// use inter_val::{Interval, Exclusive, Inclusive};
     /// let a = Interval::new(Exclusive.at(-1.0), Inclusive.at(1.0));
@@ -235,11 +559,20 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// assert_eq!(a.inf(), &a.left().limit);
     /// assert!(!a.contains(&-1.0));
     /// ```
+    ///
+    /// Available for any `PartialOrd` scalar, not only floats. Clone it for an owned value.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at("apple".to_string()).to(Exclusive.at("banana".to_string()));
+    /// let (inf, sup): (String, String) = (a.inf().clone(), a.sup().clone());
+    /// assert_eq!((inf.as_str(), sup.as_str()), ("apple", "banana"));
+    /// ```
     pub fn inf(&self) -> &T {
         self.left.inf()
     }
 
-    /// Shorthand of `.right().limit`
+    /// Value of the right boundary, i.e., shorthand of `.right().limit`.
+    /// Prefer this to reaching the `Bound` fields through `Deref`.
     /// ```
     /// use inter_val::{Interval, Exclusive, Inclusive};
     /// let a = Interval::new(Inclusive.at(-1.0), Exclusive.at(1.0));
@@ -276,6 +609,52 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         self.left.contains(t) && self.right.contains(t)
     }
 
+    /// Index range of the sorted `points` contained in `self`, found by binary search.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let timestamps = [1, 3, 3, 5, 7, 7, 9];
+    /// assert_eq!(Inclusive.at(3).to(Exclusive.at(7)).contains_sorted(&timestamps), 1..4);
+    /// assert_eq!(Exclusive.at(3).to(Inclusive.at(7)).contains_sorted(&timestamps), 3..6);
+    /// assert!(Inclusive.between(10, 20).contains_sorted(&timestamps).is_empty());
+    /// ```
+    pub fn contains_sorted(&self, points: &[T]) -> core::ops::Range<usize> {
+        let start = points.partition_point(|p| !self.left.contains(p));
+        let end = start + points[start..].partition_point(|p| self.right.contains(p));
+        start..end
+    }
+
+    /// `true` if `self` consists of a single point, i.e., *[a, a]*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// assert!(Inclusive.at(1.5).to(Inclusive.at(1.5)).is_singleton());
+    /// assert!(!Inclusive.at(1.5).to(Exclusive.at(1.6)).is_singleton());
+    /// ```
+    pub fn is_singleton(&self) -> bool {
+        self.inf() == self.sup()
+    }
+
+    /// Nearest point of the closure of `self` to `t`.
+    /// Note that the result is not contained in `self` if it is on an excluded boundary.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(4).to(Exclusive.at(7));    // [4, 7)
+    /// assert_eq!(a.clamp(2), 4);
+    /// assert_eq!(a.clamp(5), 5);
+    /// assert_eq!(a.clamp(9), 7);
+    /// ```
+    pub fn clamp(&self, t: T) -> T
+    where
+        T: Clone,
+    {
+        if t < self.left.limit {
+            self.left.limit.clone()
+        } else if self.right.limit < t {
+            self.right.limit.clone()
+        } else {
+            t
+        }
+    }
+
     /// ```
     /// use inter_val::{Inclusive, Exclusive};
     /// let a = Inclusive.at(4).to(Exclusive.at(7));    // [4, 7)
@@ -289,11 +668,49 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// ```
     pub fn dilate(self, delta: T) -> Self
     where
-        T: Clone + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+        T: Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
     {
         Self::new_(self.left.dilate(delta.clone()), self.right.dilate(delta)).unwrap()
     }
 
+    /// Same as `dilate` but returns `None` on overflow or if the result is empty.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(4).to(Exclusive.at(7));    // [4, 7)
+    /// assert_eq!(a.checked_dilate(2), Some(Inclusive.at(2).to(Exclusive.at(9))));
+    /// assert_eq!(a.checked_dilate(-2), None); // empty
+    ///
+    /// let b = Inclusive.at(0).to(Inclusive.at(i32::MAX));
+    /// assert_eq!(b.checked_dilate(1), None);  // overflow
+    /// ```
+    pub fn checked_dilate(self, delta: T) -> Option<Self>
+    where
+        T: Clone + num::CheckedAdd + num::CheckedSub,
+    {
+        Self::new_(
+            self.left.checked_dilate(delta.clone())?,
+            self.right.checked_dilate(delta)?,
+        )
+    }
+
+    /// Same as `dilate` but the boundaries saturate at the numeric bounds of `T` instead of overflowing.
+    /// Panics if the result is empty, as `dilate` does.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(i32::MIN + 1).to(Exclusive.at(i32::MAX - 1));
+    /// assert_eq!(a.saturating_dilate(3), Inclusive.at(i32::MIN).to(Exclusive.at(i32::MAX)));
+    /// ```
+    pub fn saturating_dilate(self, delta: T) -> Self
+    where
+        T: Clone + num::traits::SaturatingAdd + num::traits::SaturatingSub,
+    {
+        Self::new_(
+            self.left.saturating_dilate(delta.clone()),
+            self.right.saturating_dilate(delta),
+        )
+        .unwrap()
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -307,6 +724,29 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         self.left.includes(&other.left) && self.right.includes(&other.right)
     }
 
+    /// Partial order by inclusion: `Less` if `self` is strictly included in `other`,
+    /// `Greater` if `self` strictly includes `other`, and `None` if neither includes the other.
+    /// This is independent of the `PartialOrd` implementation.
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a: Interval<i32, BoundType> = Inclusive.at(0).to(Inclusive.at(3)).into();
+    /// let b: Interval<i32, BoundType> = Exclusive.at(0).to(Inclusive.at(3)).into();
+    /// let c: Interval<i32, BoundType> = Inclusive.at(1).to(Inclusive.at(4)).into();
+    /// assert_eq!(a.partial_cmp_by_inclusion(&a), Some(Ordering::Equal));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&b), Some(Ordering::Greater));
+    /// assert_eq!(b.partial_cmp_by_inclusion(&a), Some(Ordering::Less));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&c), None);
+    /// ```
+    pub fn partial_cmp_by_inclusion(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self.includes(other), other.includes(self)) {
+            (true, true) => Some(core::cmp::Ordering::Equal),
+            (true, false) => Some(core::cmp::Ordering::Greater),
+            (false, true) => Some(core::cmp::Ordering::Less),
+            (false, false) => None,
+        }
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -322,6 +762,8 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         is_valid_interval(left, right)
     }
 
+    /// Both operands are borrowed, and only the two boundaries of the result are cloned.
+    /// Use `overlaps()` to test without cloning at all.
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -341,6 +783,45 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         )
     }
 
+    /// Same as `intersection()` but also tells which operand supplied the left and the right boundaries of the result.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, Which};
+    /// let a = Inclusive.at(0).to(Exclusive.at(5));
+    /// let b = Inclusive.at(2).to(Exclusive.at(5));
+    /// let (i, l, r) = a.intersection_tagged(&b).unwrap();
+    /// assert_eq!(i, b);
+    /// assert_eq!((l, r), (Which::Rhs, Which::Tie));
+    /// assert!(a.intersection_tagged(&Inclusive.at(5).to(Exclusive.at(6))).is_none());
+    /// ```
+    pub fn intersection_tagged(&self, other: &Self) -> Option<(Self, Which, Which)>
+    where
+        T: Clone,
+    {
+        let which = |ord: Option<core::cmp::Ordering>| match ord {
+            Some(core::cmp::Ordering::Greater) => Which::Lhs,
+            Some(core::cmp::Ordering::Less) => Which::Rhs,
+            _ => Which::Tie,
+        };
+        let left = which(self.left.partial_cmp(&other.left));
+        let right = which(other.right.partial_cmp(&self.right));
+        self.intersection(other).map(|i| (i, left, right))
+    }
+
+    /// Clip `self` to `universe`. Same as `intersection()` but chainable.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let rows = Inclusive.at(0).to(Exclusive.at(100));
+    /// let request = Inclusive.at(90).to(Exclusive.at(120));
+    /// assert_eq!(request.clamp_to(&rows), Some(Inclusive.at(90).to(Exclusive.at(100))));
+    /// assert_eq!(Inclusive.at(150).to(Exclusive.at(200)).clamp_to(&rows), None);
+    /// ```
+    pub fn clamp_to(self, universe: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.intersection(universe)
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -357,6 +838,25 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         }
     }
 
+    /// Same as `span` but accepts an interval of different boundary types.
+    /// Where the endpoints coincide, the more inclusive boundary is chosen.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));   // [0, 3)
+    /// let b = Exclusive.at(0).to(Inclusive.at(3));   // (0, 3]
+    /// let c = a.span_mixed(b);
+    /// assert_eq!(c.kind(), (BoundType::Inclusive, BoundType::Inclusive));
+    /// assert_eq!((c.inf(), c.sup()), (&0, &3));
+    /// ```
+    pub fn span_mixed<L2, R2>(self, other: Interval<T, L2, R2>) -> Interval<T, BoundType>
+    where
+        T: Clone,
+        Self: Into<Interval<T, BoundType>>,
+        Interval<T, L2, R2>: Into<Interval<T, BoundType>>,
+    {
+        self.into().span(&other.into())
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -373,6 +873,44 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         }
     }
 
+    /// Smallest interval containing both `self` and `t`. Unlike `hull()`, `t` itself is always contained,
+    /// so the extended side becomes inclusive and the result is a general interval.
+    /// The side which is not extended keeps its boundary type. A `t` incomparable to the boundaries, e.g. NaN, is ignored.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));   // [0, 3)
+    /// assert_eq!(a.hull(5), Inclusive.at(0).to(Exclusive.at(5)));  // 5 is not contained
+    /// assert_eq!(a.extended_to_include(5), Inclusive.at(0).to(Inclusive.at(5)).into());
+    /// assert_eq!(a.extended_to_include(3), Inclusive.at(0).to(Inclusive.at(3)).into());
+    /// assert_eq!(a.extended_to_include(1), a.into());
+    ///
+    /// // Track a running range while streaming values.
+    /// let range = [4.0, -1.5, 2.0, 7.25]
+    ///     .into_iter()
+    ///     .fold(Interval::<f64, BoundType>::from(Exclusive.between(0.0, 1.0)), |acc, x| acc.extended_to_include(x));
+    /// assert_eq!(range, Inclusive.between(-1.5, 7.25).into());
+    /// assert_eq!(range.extended_to_include(f64::NAN), range);
+    /// ```
+    pub fn extended_to_include(self, t: T) -> Interval<T, BoundType>
+    where
+        Self: Into<Interval<T, BoundType>>,
+        T: Clone,
+    {
+        let mut general: Interval<T, BoundType> = self.into();
+        if general.left.limit.partial_cmp(&t).is_none()
+            || general.right.limit.partial_cmp(&t).is_none()
+        {
+            return general;
+        }
+        if !general.left.contains(&t) {
+            general.left = BoundType::Inclusive.at(t.clone()).into();
+        }
+        if !general.right.contains(&t) {
+            general.right = BoundType::Inclusive.at(t).into();
+        }
+        general
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -382,6 +920,8 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     pub fn gap(&self, other: &Self) -> Option<Interval<T, R::Flip, L::Flip>>
     where
         T: Clone,
+        L: Flip,
+        R: Flip,
         L::Flip: BoundaryOf<Right>,
         R::Flip: BoundaryOf<Left>,
     {
@@ -389,6 +929,138 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
             .or_else(|| Interval::new_(other.right.clone().flip(), self.left.clone().flip()))
     }
 
+    /// `true` if `self` and `other` are disjoint but their union is a single interval.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));
+    /// let b = Inclusive.at(3).to(Exclusive.at(5));
+    /// let c = Inclusive.at(4).to(Exclusive.at(5));
+    /// assert!(a.is_adjacent(&b) && b.is_adjacent(&a));
+    /// assert!(!a.is_adjacent(&c));
+    /// assert!(!a.is_adjacent(&a));    // overlapping
+    /// ```
+    pub fn is_adjacent(&self, other: &Self) -> bool
+    where
+        T: Clone,
+        L: Flip,
+        R: Flip,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        !self.overlaps(other) && self.gap(other).is_none()
+    }
+
+    /// Gaps between `intervals` sorted by their left boundaries, i.e., the complement of their union within their span.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let meetings = [
+    ///     Inclusive.at(9).to(Exclusive.at(11)),
+    ///     Inclusive.at(10).to(Exclusive.at(12)),
+    ///     Inclusive.at(12).to(Exclusive.at(13)),  // touching
+    ///     Inclusive.at(14).to(Exclusive.at(17)),
+    ///     Inclusive.at(15).to(Exclusive.at(16)),  // nested
+    ///     Inclusive.at(18).to(Exclusive.at(19)),
+    /// ];
+    /// let free: Vec<_> = Interval::gaps(&meetings).collect();
+    /// assert_eq!(free, vec![Inclusive.at(13).to(Exclusive.at(14)), Inclusive.at(17).to(Exclusive.at(18))]);
+    /// ```
+    pub fn gaps(intervals: &[Self]) -> impl Iterator<Item = Interval<T, R::Flip, L::Flip>> + '_
+    where
+        T: Clone,
+        L: Flip,
+        R: Flip,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        // Consecutive runs of `coalesce()` are always separated by a gap.
+        let mut runs = Self::coalesce(intervals.iter().cloned()).peekable();
+        core::iter::from_fn(move || {
+            let run = runs.next()?;
+            run.gap(runs.peek()?)
+        })
+    }
+
+    /// Merge `items` sorted by their left boundaries into disjoint runs, splitting only where a gap exists.
+    /// Overlapping and adjacent items are merged. It is the streaming counterpart of `span_many()`,
+    /// and the primitive which `gaps()` and `IntervalSet::new()` are built on.
+    /// It yields the runs lazily instead of returning a `Vec`, so that it is available without the `alloc` feature.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let reads = [
+    ///     Inclusive.at(0).to(Exclusive.at(5)),
+    ///     Inclusive.at(3).to(Exclusive.at(8)),
+    ///     Inclusive.at(8).to(Exclusive.at(9)),    // adjacent
+    ///     Inclusive.at(12).to(Exclusive.at(20)),
+    ///     Inclusive.at(14).to(Exclusive.at(15)),  // nested
+    /// ];
+    /// let runs: Vec<_> = Interval::coalesce(reads).collect();
+    /// assert_eq!(runs, vec![Inclusive.at(0).to(Exclusive.at(9)), Inclusive.at(12).to(Exclusive.at(20))]);
+    ///
+    /// let a = Exclusive.at(0).to(Exclusive.at(1));
+    /// let b = Exclusive.at(1).to(Exclusive.at(2));
+    /// assert_eq!(Interval::coalesce([a, b]).count(), 2); // 1 is in neither
+    /// ```
+    pub fn coalesce(items: impl IntoIterator<Item = Self>) -> impl Iterator<Item = Self>
+    where
+        T: Clone,
+        L: Flip,
+        R: Flip,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        let mut items = items.into_iter().peekable();
+        core::iter::from_fn(move || {
+            let mut run = items.next()?;
+            while let Some(item) = items.next_if(|item| run.gap(item).is_none()) {
+                run = run.span(&item);
+            }
+            Some(run)
+        })
+    }
+
+    /// Pairwise intersections of two sorted sequences of disjoint intervals, in sorted order.
+    /// It runs in *O(m + n)* by merge-joining the two sequences lazily.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let coverage = [
+    ///     Inclusive.at(0).to(Exclusive.at(10)),
+    ///     Inclusive.at(20).to(Exclusive.at(30)),
+    /// ];
+    /// let genes = [
+    ///     Inclusive.at(5).to(Exclusive.at(8)),
+    ///     Inclusive.at(9).to(Exclusive.at(22)),
+    ///     Inclusive.at(25).to(Exclusive.at(40)),
+    /// ];
+    /// let common: Vec<_> = Interval::intersect_sorted(coverage, genes).collect();
+    /// assert_eq!(common, vec![
+    ///     Inclusive.at(5).to(Exclusive.at(8)),
+    ///     Inclusive.at(9).to(Exclusive.at(10)),
+    ///     Inclusive.at(20).to(Exclusive.at(22)),
+    ///     Inclusive.at(25).to(Exclusive.at(30)),
+    /// ]);
+    /// ```
+    pub fn intersect_sorted(
+        a: impl IntoIterator<Item = Self>,
+        b: impl IntoIterator<Item = Self>,
+    ) -> impl Iterator<Item = Self>
+    where
+        T: Clone,
+    {
+        let (mut a, mut b) = (a.into_iter().peekable(), b.into_iter().peekable());
+        core::iter::from_fn(move || loop {
+            let (x, y) = (a.peek()?, b.peek()?);
+            let isect = x.intersection(y);
+            if x.right <= y.right {
+                a.next();
+            } else {
+                b.next();
+            }
+            if isect.is_some() {
+                return isect;
+            }
+        })
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -404,6 +1076,8 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     pub fn union(&self, other: &Self) -> IntervalUnion<T, L, R>
     where
         T: Clone,
+        L: Flip,
+        R: Flip,
         L::Flip: BoundaryOf<Right>,
         R::Flip: BoundaryOf<Left>,
     {
@@ -413,9 +1087,49 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         }
     }
 
+    /// Split `self` at `t` into the part below `t` and the part at or above `t`.
+    /// Each part is `None` if it is empty. When `t` is out of `self`, the other part is `self` itself.
+    /// ```
+    /// use inter_val::{Interval, BoundType, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10)); // [0, 10)
+    /// let split = a.split_at(4);
+    /// assert_eq!(split.lower.unwrap(), Inclusive.at(0).to(BoundType::Exclusive.at(4))); // [0, 4)
+    /// assert_eq!(split.upper.unwrap(), BoundType::Inclusive.at(4).to(Exclusive.at(10))); // [4, 10)
+    ///
+    /// let split = a.split_at(10);
+    /// assert_eq!(split.lower.unwrap(), Inclusive.at(0).to(BoundType::Exclusive.at(10))); // [0, 10)
+    /// assert!(split.upper.is_none());
+    ///
+    /// let split = a.split_at(-1);
+    /// assert!(split.lower.is_none());
+    /// assert_eq!(split.upper.unwrap(), BoundType::Inclusive.at(0).to(Exclusive.at(10))); // [0, 10)
+    /// ```
+    pub fn split_at(self, t: T) -> IntervalSplit<T, L, R>
+    where
+        T: Clone,
+        L: Into<BoundType>,
+        R: Into<BoundType>,
+    {
+        let lower_right = if self.right.contains(&t) {
+            BoundType::Exclusive.at(t.clone())
+        } else {
+            BoundType::at(self.right.bound_type.into(), self.right.limit.clone())
+        };
+        let upper_left = if self.left.contains(&t) {
+            BoundType::Inclusive.at(t)
+        } else {
+            BoundType::at(self.left.bound_type.into(), self.left.limit.clone())
+        };
+        IntervalSplit {
+            lower: Interval::new_(self.left, lower_right.into()),
+            upper: Interval::new_(upper_left.into(), self.right),
+        }
+    }
+
     pub fn lower_bound(&self) -> RightBounded<T, L::Flip>
     where
         T: Clone,
+        L: Flip,
     {
         self.left.clone().flip()
     }
@@ -423,10 +1137,14 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     pub fn upper_bound(&self) -> LeftBounded<T, R::Flip>
     where
         T: Clone,
+        R: Flip,
     {
         self.right.clone().flip()
     }
 
+    /// Length of the interval, i.e., `sup() - inf()`.
+    /// The result is typed by `T::Output`, so that e.g. the measure of an interval of time points is a duration.
+    /// It is *+∞* if a side is `Unbounded`.
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(2.1).to(Inclusive.at(5.3));
@@ -434,14 +1152,60 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     ///
     /// let a = Exclusive.at(0).to(Exclusive.at(1));    // (0, 1)
     /// assert_eq!(a.measure(), 1);
+    ///
+    /// use std::time::{Duration, Instant};
+    /// let t = Instant::now();
+    /// let a = Inclusive.at(t).to(Exclusive.at(t + Duration::from_secs(3)));
+    /// assert_eq!(a.measure(), Duration::from_secs(3));
     /// ```
-    pub fn measure(&self) -> T
+    pub fn measure(&self) -> T::Output
     where
-        T: Clone + std::ops::Sub<Output = T>,
+        T: Clone + core::ops::Sub,
     {
         self.sup().clone() - self.inf().clone()
     }
 
+    /// Measure of the intersection, or zero if `self` and `other` are disjoint.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(3.0));
+    /// let b = Inclusive.at(2.5).to(Exclusive.at(5.0));
+    /// let c = Inclusive.at(4.0).to(Exclusive.at(5.0));
+    /// assert_eq!(a.overlap_measure(&b), 0.5);
+    /// assert_eq!(a.overlap_measure(&c), 0.0);
+    /// ```
+    pub fn overlap_measure(&self, other: &Self) -> T
+    where
+        T: Clone + core::ops::Sub<Output = T> + num::Zero,
+    {
+        self.intersection(other)
+            .map_or_else(T::zero, |i| i.measure())
+    }
+
+    /// Midpoint of `self`, computed as `inf + (sup - inf) / 2` so that it does not overflow unless `measure()` does.
+    /// Rounded by the division of `T`, i.e., toward `inf()` for integers. `NaN` if a side is `Unbounded`, since a half-line has no center.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(2.5).to(Inclusive.at(5.5));
+    /// assert_eq!(a.center(), 4.0);
+    ///
+    /// let a = Inclusive.at(2).to(Exclusive.at(7));
+    /// assert_eq!(a.center(), 4);
+    /// assert_eq!(Inclusive.at(-7).to(Exclusive.at(-2)).center(), -5);
+    /// assert_eq!(Inclusive.between(i32::MAX - 2, i32::MAX).center(), i32::MAX - 1);
+    ///
+    /// use inter_val::Unbounded;
+    /// assert!(Unbounded::neg_infinity().to(Inclusive.at(3.0_f64)).center().is_nan());
+    /// ```
+    pub fn center(&self) -> T
+    where
+        T: Clone + num::Num,
+    {
+        let inf = self.inf().clone();
+        inf.clone() + (self.sup().clone() - inf) / (T::one() + T::one())
+    }
+
+    /// Span of all `items`, which may be given either by value or by reference.
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive, Nullable};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));  // [0, 3)
@@ -451,11 +1215,15 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// assert_eq!(span.left().limit, 0);
     /// assert_eq!(span.right().limit, 10);
     ///
+    /// // Borrowed items are not consumed.
+    /// let items = [a, b, c];
+    /// assert_eq!(Interval::span_many(&items), Some(span));
+    ///
     /// // Sum for Nullable<Interval> can be used as well.
     /// let sum: Nullable<Interval<_, _, _>> = vec![a, b, c].into_iter().sum();
     /// assert_eq!(sum.unwrap(), span);
     /// ```
-    pub fn span_many<A: std::borrow::Borrow<Self>>(
+    pub fn span_many<A: core::borrow::Borrow<Self>>(
         items: impl IntoIterator<Item = A>,
     ) -> Option<Self>
     where
@@ -466,11 +1234,37 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         Some(items.fold(first, |acc, item| acc.span(item.borrow())))
     }
 
+    /// Intersection of all `items`, which may be given either by value or by reference.
+    /// Returns `None` if `items` is empty or the intersection gets empty, stopping at that point.
     /// ```
-    /// use inter_val::{Interval, Nullable};
-    /// let hull = Interval::<_>::hull_many(vec![3, 9, 2, 5]).unwrap(); // [2, 9]
-    /// assert_eq!(hull.inf(), &2);
-    /// assert_eq!(hull.sup(), &9);
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let constraints = [
+    ///     Inclusive.at(0.0).to(Exclusive.at(10.0)),
+    ///     Inclusive.at(2.5).to(Exclusive.at(20.0)),
+    ///     Inclusive.at(-5.0).to(Exclusive.at(7.0)),
+    /// ];
+    /// let feasible = Interval::intersection_many(&constraints);
+    /// assert_eq!(feasible, Some(Inclusive.at(2.5).to(Exclusive.at(7.0))));
+    ///
+    /// let contradictory = constraints.into_iter().chain([Inclusive.at(7.0).to(Exclusive.at(8.0))]);
+    /// assert_eq!(Interval::intersection_many(contradictory), None);
+    /// ```
+    pub fn intersection_many<A: core::borrow::Borrow<Self>>(
+        items: impl IntoIterator<Item = A>,
+    ) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let mut items = items.into_iter();
+        let first = items.next()?.borrow().clone();
+        items.try_fold(first, |acc, item| acc.intersection(item.borrow()))
+    }
+
+    /// ```
+    /// use inter_val::{Interval, Nullable};
+    /// let hull = Interval::<_>::hull_many(vec![3, 9, 2, 5]).unwrap(); // [2, 9]
+    /// assert_eq!(hull.inf(), &2);
+    /// assert_eq!(hull.sup(), &9);
     ///
     /// let hull = Interval::<_>::hull_many(vec![3.1, 9.2, 2.3, 5.4]).unwrap(); // [2.3, 9.2]
     /// assert_eq!(hull.inf(), &2.3);
@@ -498,8 +1292,11 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     }
 }
 
-impl<T: PartialOrd, L: BoundaryOf<Left, Flip = R>, R: BoundaryOf<Right, Flip = L>>
-    Interval<T, L, R>
+impl<
+        T: PartialOrd,
+        L: BoundaryOf<Left> + Flip<Flip = R>,
+        R: BoundaryOf<Right> + Flip<Flip = L>,
+    > Interval<T, L, R>
 {
     /// Difference is defined only for `Interval<T, Inclusive, Exclusive>`, `Interval<T, Exclusive, Inclusive>`, and `Interval<T, BoundType>`.
     /// ```
@@ -522,14 +1319,323 @@ impl<T: PartialOrd, L: BoundaryOf<Left, Flip = R>, R: BoundaryOf<Right, Flip = L
     }
 }
 
+impl<T: PartialOrd> Interval<T, Inclusive> {
+    /// Closed interval *[a, b]*. Returns `None` if `b < a`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// assert_eq!(Interval::closed(0, 3), Some(Inclusive.at(0).to(Inclusive.at(3))));
+    /// assert_eq!(Interval::closed(3, 3), Some(Inclusive.at(3).to(Inclusive.at(3))));
+    /// assert_eq!(Interval::closed(3, 0), None);
+    /// ```
+    pub fn closed(a: T, b: T) -> Option<Self> {
+        Self::try_new(Inclusive.at(a), Inclusive.at(b))
+    }
+
+    /// Closed interval *[center - radius, center + radius]*.
+    /// Returns `None` if `radius` is negative or NaN.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Interval::try_from_center_radius(10.0, 0.5).unwrap();
+    /// assert_eq!(a, Inclusive.at(9.5).to(Inclusive.at(10.5)));
+    /// assert_eq!(a.center(), 10.0);
+    /// assert!(Interval::try_from_center_radius(10.0, -0.5).is_none());
+    /// assert!(Interval::try_from_center_radius(10.0, f64::NAN).is_none());
+    /// ```
+    pub fn try_from_center_radius(center: T, radius: T) -> Option<Self>
+    where
+        T: Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        Self::try_new(
+            Inclusive.at(center.clone() - radius.clone()),
+            Inclusive.at(center + radius),
+        )
+    }
+
+    /// Same as `try_from_center_radius()` but panics if `radius` is negative or NaN.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// assert_eq!(Interval::from_center_radius(3, 2), Inclusive.between(1, 5));
+    /// ```
+    pub fn from_center_radius(center: T, radius: T) -> Self
+    where
+        T: Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        Self::try_from_center_radius(center, radius).unwrap()
+    }
+}
+
+impl<T: num::Float> Interval<T, Inclusive> {
+    /// Tightest closed interval containing all `samples`, i.e., `hull_many()` rejecting NaN.
+    /// Returns `Ok(None)` if `samples` is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Interval::from_samples([0.5, -1.25, 3.0, 2.0]).unwrap();
+    /// assert_eq!(a, Some(Inclusive.between(-1.25, 3.0)));
+    /// assert_eq!(Interval::<f64>::from_samples([]).unwrap(), None);
+    /// assert!(Interval::from_samples([0.5, f64::NAN, 3.0]).is_err());
+    /// ```
+    pub fn from_samples(samples: impl IntoIterator<Item = T>) -> Result<Option<Self>, FloatIsNan> {
+        let mut bounds: Option<(T, T)> = None;
+        for x in samples {
+            if x.is_nan() {
+                return Err(FloatIsNan);
+            }
+            bounds = Some(bounds.map_or((x, x), |(inf, sup)| (inf.min(x), sup.max(x))));
+        }
+        Ok(bounds.map(|(inf, sup)| Self::new(Inclusive.at(inf), Inclusive.at(sup))))
+    }
+}
+
+impl<T: PartialOrd> Interval<T, Exclusive> {
+    /// Open interval *(a, b)*. Returns `None` if `b <= a`.
+    /// ```
+    /// use inter_val::{Interval, Exclusive};
+    /// assert_eq!(Interval::open(0, 3), Some(Exclusive.at(0).to(Exclusive.at(3))));
+    /// assert_eq!(Interval::open(3, 3), None);
+    /// ```
+    pub fn open(a: T, b: T) -> Option<Self> {
+        Self::try_new(Exclusive.at(a), Exclusive.at(b))
+    }
+}
+
+impl<T: num::Float> Interval<T, Exclusive> {
+    /// Closed interval strictly inside `self`, shrinking both boundaries inward by `eps`, e.g.,
+    /// to pass an open interval to an API which only takes inclusive boundaries.
+    /// Returns `None` if `eps` is not positive or `2 * eps >= measure()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.between(0.0, 1.0); // (0, 1)
+    /// assert_eq!(a.to_closed_nudged(0.25), Some(Inclusive.between(0.25, 0.75)));
+    /// assert_eq!(a.to_closed_nudged(0.5), None);
+    /// assert_eq!(a.to_closed_nudged(0.0), None);
+    /// ```
+    pub fn to_closed_nudged(&self, eps: T) -> Option<Interval<T>> {
+        if eps > T::zero() && eps + eps < self.measure() {
+            Interval::try_new(
+                Inclusive.at(*self.inf() + eps),
+                Inclusive.at(*self.sup() - eps),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: PartialOrd> Interval<T, Inclusive, Exclusive> {
+    /// Right half-open interval *[a, b)*. Returns `None` if `b <= a`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// assert_eq!(Interval::closed_open(0.0, 1.5), Some(Inclusive.at(0.0).to(Exclusive.at(1.5))));
+    /// assert_eq!(Interval::closed_open(1.5, 1.5), None);
+    /// ```
+    pub fn closed_open(a: T, b: T) -> Option<Self> {
+        Self::try_new(Inclusive.at(a), Exclusive.at(b))
+    }
+}
+
+impl<T: PartialOrd> Interval<T, Exclusive, Inclusive> {
+    /// Left half-open interval *(a, b]*. Returns `None` if `b <= a`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// assert_eq!(Interval::open_closed(0.0, 1.5), Some(Exclusive.at(0.0).to(Inclusive.at(1.5))));
+    /// assert_eq!(Interval::open_closed(1.5, 1.5), None);
+    /// ```
+    pub fn open_closed(a: T, b: T) -> Option<Self> {
+        Self::try_new(Exclusive.at(a), Inclusive.at(b))
+    }
+}
+
+impl<T: num::PrimInt> Interval<T, Inclusive, Exclusive> {
+    /// Half-open interval *[start, start + len)*, e.g., a memory region given by offset and length.
+    /// Returns `None` if `len` is zero or not positive, since an empty *[start, start)* is not an `Interval`,
+    /// or if `start + len` overflows.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Interval::from_start_len(100_i64, 16).unwrap();
+    /// assert_eq!(a, Inclusive.at(100).to(Exclusive.at(116)));
+    /// assert_eq!(a.to_start_len(), Some((100, 16)));
+    /// assert!(Interval::from_start_len(100_i64, 0).is_none());
+    /// assert!(Interval::from_start_len(100_i64, -1).is_none());
+    /// assert!(Interval::from_start_len(u8::MAX, 1).is_none());
+    /// ```
+    pub fn from_start_len(start: T, len: T) -> Option<Self> {
+        let end = start.checked_add(&len)?;
+        Self::try_new(Inclusive.at(start), Exclusive.at(end))
+    }
+
+    /// Inverse of `from_start_len()`. Returns `None` if the length does not fit in `T`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// assert_eq!(Inclusive.at(-100_i8).to(Exclusive.at(20)).to_start_len(), Some((-100, 120)));
+    /// assert_eq!(Inclusive.at(-100_i8).to(Exclusive.at(100)).to_start_len(), None);
+    /// ```
+    pub fn to_start_len(&self) -> Option<(T, T)> {
+        Some((*self.inf(), self.sup().checked_sub(self.inf())?))
+    }
+}
+
 impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R> {
+    /// `true` if `t` is within `epsilon` of the closure of `self`, regardless of the boundary types.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(0.3));
+    /// let t = 0.1 + 0.2;  // 0.30000000000000004
+    /// assert!(!a.contains(&t));
+    /// assert!(a.contains_approx(t, 1e-9));
+    /// assert!(a.contains_approx(0.3, 0.0));
+    /// assert!(!a.contains_approx(-0.1, 1e-9));
+    /// ```
+    pub fn contains_approx(&self, t: T, epsilon: T) -> bool {
+        *self.inf() - epsilon <= t && t <= *self.sup() + epsilon
+    }
+
+    /// Signed distance from `t` to the nearest boundary, positive inside and negative outside.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));
+    /// assert_eq!(a.signed_distance(3.0), 3.0);
+    /// assert_eq!(a.signed_distance(8.0), 2.0);
+    /// assert_eq!(a.signed_distance(-1.5), -1.5);
+    /// assert_eq!(a.signed_distance(12.0), -2.0);
+    /// ```
+    pub fn signed_distance(&self, t: T) -> T {
+        (t - *self.inf()).min(*self.sup() - t)
+    }
+
+    /// How deep `t` is inside `self`, i.e., the distance to the nearest boundary, or `None` if `t` is not contained.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));
+    /// assert_eq!(a.penetration(&3.0), Some(3.0));
+    /// assert_eq!(a.penetration(&0.0), Some(0.0));
+    /// assert_eq!(a.penetration(&10.0), None);
+    /// ```
+    pub fn penetration(&self, t: &T) -> Option<T> {
+        self.contains(t).then(|| self.signed_distance(*t))
+    }
+
+    /// Subdivide `self` into `n` contiguous pieces of equal width.
+    /// Inner pieces are right half-open, *[x_i, x_{i+1})*, while the first and the last pieces keep the boundaries of `self`,
+    /// so the pieces exactly tile `self` without gaps or overlaps.
+    /// Returns `None` unless all the `n` pieces are non-empty, e.g., if `self` is a single point, its measure is not finite,
+    /// or rounding makes some edges coincide.
+    /// ```
+    /// use inter_val::{Interval, BoundType, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(3.0)); // [0, 3]
+    /// let pieces: Vec<_> = a.subdivide(3).unwrap().collect();
+    /// assert_eq!(pieces.len(), 3);
+    /// assert_eq!(pieces[0], BoundType::Inclusive.at(0.0).to(BoundType::Exclusive.at(1.0))); // [0, 1)
+    /// assert_eq!(pieces[1], BoundType::Inclusive.at(1.0).to(BoundType::Exclusive.at(2.0))); // [1, 2)
+    /// assert_eq!(pieces[2], BoundType::Inclusive.at(2.0).to(BoundType::Inclusive.at(3.0))); // [2, 3]
+    ///
+    /// assert_eq!(a.subdivide(0).unwrap().count(), 0);
+    /// assert!(Inclusive.between(1.0, 1.0).subdivide(2).is_none());
+    /// assert!(Inclusive.at(1e16).to(Exclusive.at(1e16 + 4.0)).subdivide(3).is_none()); // inner edges round to the same float
+    /// ```
+    pub fn subdivide(self, n: usize) -> Option<impl Iterator<Item = Interval<T, BoundType>>>
+    where
+        L: Into<BoundType>,
+        R: Into<BoundType>,
+    {
+        let left = self.left.bound_type.into();
+        let right = self.right.bound_type.into();
+        let (inf, sup, measure) = (self.left.limit, self.right.limit, self.measure());
+        if !measure.is_finite() {
+            return None;
+        }
+        // Monotonic in `i` and clamped to `[inf, sup]`, so that pieces never overlap.
+        let edge = move |i: usize| {
+            if i == 0 {
+                inf
+            } else if i == n {
+                sup
+            } else {
+                let ratio = num::cast::<_, T>(i).unwrap() / num::cast(n).unwrap();
+                (inf + measure * ratio).min(sup)
+            }
+        };
+        let piece = move |i: usize| {
+            let l = if i == 0 { left } else { BoundType::Inclusive };
+            let r = if i + 1 == n {
+                right
+            } else {
+                BoundType::Exclusive
+            };
+            Interval::try_new(l.at(edge(i)), r.at(edge(i + 1)))
+        };
+        (0..n)
+            .all(|i| piece(i).is_some())
+            .then(|| (0..n).filter_map(piece))
+    }
+
+    /// `n` evenly spaced points from `inf()` to `sup()` inclusive, like NumPy's `linspace`.
+    /// Yields only `inf()` if `n == 1`, and nothing if `n == 0`.
+    /// Note that the end points are yielded even if they are excluded from `self`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(1.0));
+    /// assert_eq!(a.linspace(5).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(a.linspace(1).collect::<Vec<_>>(), vec![0.0]);
+    /// assert_eq!(a.linspace(0).count(), 0);
+    /// ```
+    pub fn linspace(&self, n: usize) -> impl Iterator<Item = T> {
+        let (inf, sup) = (self.left.limit, self.right.limit);
+        (0..n).map(move |i| {
+            if i == 0 {
+                inf
+            } else if i + 1 == n {
+                sup
+            } else {
+                let ratio = num::cast::<_, T>(i).unwrap() / num::cast(n - 1).unwrap();
+                inf + (sup - inf) * ratio
+            }
+        })
+    }
+
+    /// Point at the fraction `t` of `self`, i.e., `inf() + t * measure()`.
     /// ```
     /// use inter_val::{Interval, Inclusive};
-    /// let a = Inclusive.at(2.1).to(Inclusive.at(5.3));
-    /// assert_eq!(a.center(), (2.1 + 5.3) / 2.0);
+    /// let a = Inclusive.at(2.0).to(Inclusive.at(6.0));
+    /// assert_eq!(a.lerp(0.0), 2.0);
+    /// assert_eq!(a.lerp(0.5), 4.0);
+    /// assert_eq!(a.lerp(1.5), 8.0);
+    /// ```
+    pub fn lerp(&self, t: T) -> T {
+        *self.inf() + t * self.measure()
+    }
+
+    /// Inverse of `lerp()`, i.e., fraction of `value` in `self`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(2.0).to(Inclusive.at(6.0));
+    /// assert_eq!(a.inv_lerp(3.0), 0.25);
+    /// assert_eq!(a.inv_lerp(a.lerp(0.75)), 0.75);
     /// ```
-    pub fn center(&self) -> T {
-        (self.left.limit + self.right.limit) / (T::one() + T::one())
+    pub fn inv_lerp(&self, value: T) -> T {
+        (value - *self.inf()) / self.measure()
+    }
+
+    /// Blend `self` and `other` at the fraction `t` boundary by boundary, keeping boundary types.
+    /// The result is always valid for `t` in *[0, 1]*.
+    /// Otherwise it is extrapolated and `None` is returned if it becomes empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let near = Inclusive.at(0.1).to(Exclusive.at(100.0));
+    /// let far = Inclusive.at(1.0).to(Exclusive.at(1000.0));
+    /// assert_eq!(near.interpolate(&far, 0.0), Some(near));
+    /// assert_eq!(near.interpolate(&far, 1.0), Some(far));
+    /// assert_eq!(near.interpolate(&far, 0.5), Some(Inclusive.at(0.55).to(Exclusive.at(550.0))));
+    ///
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));
+    /// let b = Inclusive.at(9.0).to(Inclusive.at(10.0));
+    /// assert_eq!(a.interpolate(&b, 2.0), None);  // extrapolated to [18, 10]
+    /// ```
+    pub fn interpolate(&self, other: &Self, t: T) -> Option<Self> {
+        let blend = |a: T, b: T| a + t * (b - a);
+        Self::new_(
+            self.left.map(|a| blend(a, *other.inf())),
+            self.right.map(|a| blend(a, *other.sup())),
+        )
     }
 
     /// IoU - Intersection over Union.
@@ -550,6 +1656,365 @@ impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
             })
             .unwrap_or(T::zero())
     }
+
+    /// Fraction of `self` covered by `other`, i.e., intersection over `self`. Unlike `iou()`, this is asymmetric.
+    /// A single point `self` is either fully covered or not at all.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let truth = Inclusive.at(0.0).to(Inclusive.at(4.0));
+    /// let prediction = Inclusive.at(2.0).to(Inclusive.at(10.0));
+    /// assert_eq!(truth.coverage(&prediction), 0.5);
+    /// assert_eq!(prediction.coverage(&truth), 0.25);
+    /// assert_eq!(truth.coverage(&Inclusive.at(5.0).to(Inclusive.at(6.0))), 0.0);
+    /// assert_eq!(Inclusive.at(3.0).to(Inclusive.at(3.0)).coverage(&truth), 1.0);
+    /// ```
+    pub fn coverage(&self, other: &Self) -> T {
+        self.intersection(other)
+            .map(|intersection| {
+                let measure = self.measure();
+                if measure.is_zero() {
+                    T::one()
+                } else {
+                    intersection.measure() / measure
+                }
+            })
+            .unwrap_or(T::zero())
+    }
+
+    /// Hausdorff distance between the closures of `self` and `other`, i.e., *max(|a₀ - b₀|, |a₁ - b₁|)*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));
+    /// let b = Inclusive.at(2.0).to(Inclusive.at(7.0));     // nested
+    /// let c = Inclusive.at(20.0).to(Inclusive.at(25.0));   // disjoint
+    /// assert_eq!(a.hausdorff_distance(&a), 0.0);
+    /// assert_eq!(a.hausdorff_distance(&b), 3.0);
+    /// assert_eq!(a.hausdorff_distance(&c), 20.0);
+    /// ```
+    pub fn hausdorff_distance(&self, other: &Self) -> T {
+        let d_inf = (*self.inf() - *other.inf()).abs();
+        let d_sup = (*self.sup() - *other.sup()).abs();
+        d_inf.max(d_sup)
+    }
+
+    /// Smallest closed interval containing `self` whose boundaries are multiples of `step`.
+    /// The sign of `step` is ignored, and `None` is returned if `step` is zero or not finite.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(-0.7).to(Inclusive.at(1.2));
+    /// assert_eq!(a.snap_to_grid(0.5), Some(Inclusive.at(-1.0).to(Inclusive.at(1.5))));
+    /// assert_eq!(a.snap_to_grid(0.25), Some(Inclusive.at(-0.75).to(Inclusive.at(1.25))));
+    /// assert_eq!(a.snap_to_grid(-0.5), a.snap_to_grid(0.5));
+    /// assert_eq!(a.snap_to_grid(0.0), None);
+    /// assert_eq!(a.snap_to_grid(f64::NAN), None);
+    /// ```
+    pub fn snap_to_grid(&self, step: T) -> Option<Interval<T, Inclusive>> {
+        if step.is_zero() || !step.is_finite() {
+            return None;
+        }
+        let step = step.abs();
+        Interval::try_new(
+            Inclusive.at((self.left.limit / step).floor() * step),
+            Inclusive.at((self.right.limit / step).ceil() * step),
+        )
+    }
+
+    /// Smallest closed interval containing `self` whose boundaries are elements of `ticks`.
+    /// A boundary beyond the range of `ticks` is kept as it is.
+    /// `ticks` must be sorted in ascending order; this is checked in debug builds, and violating it may panic.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let ticks = [0.0, 1.0, 2.0, 5.0, 10.0];
+    /// let a = Exclusive.at(1.5).to(Inclusive.at(2.0));
+    /// assert_eq!(a.snap_to_ticks(&ticks), Inclusive.at(1.0).to(Inclusive.at(2.0)));
+    ///
+    /// let a = Inclusive.at(-3.0).to(Exclusive.at(7.0));
+    /// assert_eq!(a.snap_to_ticks(&ticks), Inclusive.at(-3.0).to(Inclusive.at(10.0)));
+    /// ```
+    pub fn snap_to_ticks(&self, ticks: &[T]) -> Interval<T, Inclusive> {
+        debug_assert!(
+            ticks.windows(2).all(|w| w[0] <= w[1]),
+            "ticks must be sorted"
+        );
+        let (inf, sup) = (*self.inf(), *self.sup());
+        let i = ticks.partition_point(|&t| t <= inf);
+        let j = ticks.partition_point(|&t| t < sup);
+        Interval::try_new(
+            Inclusive.at(i.checked_sub(1).map_or(inf, |i| ticks[i])),
+            Inclusive.at(ticks.get(j).copied().unwrap_or(sup)),
+        )
+        .expect("ticks must be sorted")
+    }
+
+    /// Move `self` into `container`, like clamping a scroll viewport to the content.
+    /// `self` is translated by the minimum distance to fit first, keeping its width,
+    /// and only if it is not narrower than `container`, `container` itself is returned.
+    /// A side moved onto the boundary of `container` takes the boundary type of `container` there.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let content = Inclusive.at(0.0).to(Exclusive.at(100.0));
+    /// let view = Inclusive.at(90.0).to(Exclusive.at(120.0));
+    /// assert_eq!(view.fit_within(content), Inclusive.at(70.0).to(Exclusive.at(100.0)));
+    ///
+    /// let view = Inclusive.at(-5.0).to(Exclusive.at(20.0));
+    /// assert_eq!(view.fit_within(content), Inclusive.at(0.0).to(Exclusive.at(25.0)));
+    ///
+    /// let view = Inclusive.at(10.0).to(Exclusive.at(20.0));
+    /// assert_eq!(view.fit_within(content), view);
+    ///
+    /// let view = Inclusive.at(-10.0).to(Exclusive.at(150.0));
+    /// assert_eq!(view.fit_within(content), content);
+    ///
+    /// let content: Interval<f64, BoundType> = content.into();
+    /// let view: Interval<f64, BoundType> = Inclusive.between(90.0, 120.0).into();
+    /// let fitted = view.fit_within(content);
+    /// assert_eq!(fitted, BoundType::Inclusive.at(70.0).to(BoundType::Exclusive.at(100.0)));
+    /// assert!(content.includes(&fitted));
+    /// ```
+    pub fn fit_within(self, container: Self) -> Self {
+        if self.measure() >= container.measure() {
+            return container;
+        }
+        let (inf, sup) = (*self.inf(), *self.sup());
+        let (min, max) = (*container.inf(), *container.sup());
+        if inf < min {
+            let sup = sup + (min - inf);
+            Interval {
+                left: container.left,
+                right: if sup < max {
+                    self.right.map(|_| sup)
+                } else {
+                    container.right
+                },
+            }
+        } else if max < sup {
+            let inf = inf - (sup - max);
+            Interval {
+                left: if min < inf {
+                    self.left.map(|_| inf)
+                } else {
+                    container.left
+                },
+                right: container.right,
+            }
+        } else {
+            self
+        }
+    }
+
+    /// "Nice numbers" for a chart axis: a step of 1, 2, or 5 times a power of ten which yields about `target_ticks` gridlines,
+    /// and `snap_to_grid()` by the step. A zero-width interval gives itself and the step zero.
+    /// Returns `None` if `measure()` is not finite.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.between(0.37, 4.91);
+    /// assert_eq!(a.nice_bounds(5), Some((Inclusive.between(0.0, 5.0), 1.0)));
+    ///
+    /// let a = Exclusive.at(-3.2).to(Inclusive.at(97.5));
+    /// assert_eq!(a.nice_bounds(6), Some((Inclusive.between(-50.0, 100.0), 50.0)));
+    /// assert_eq!(a.nice_bounds(11), Some((Inclusive.between(-20.0, 100.0), 20.0)));
+    ///
+    /// assert_eq!(Inclusive.between(0.0, f64::INFINITY).nice_bounds(5), None);
+    /// ```
+    pub fn nice_bounds(&self, target_ticks: usize) -> Option<(Interval<T, Inclusive>, T)> {
+        fn nice_num<T: num::Float>(x: T, round: bool) -> T {
+            let c = |v: f64| T::from(v).unwrap();
+            let base = c(10.0).powf(x.log10().floor());
+            let f = x / base;
+            let nice = if round {
+                [(1.5, 1.0), (3.0, 2.0), (7.0, 5.0)]
+                    .into_iter()
+                    .find(|&(limit, _)| f < c(limit))
+            } else {
+                [(1.0, 1.0), (2.0, 2.0), (5.0, 5.0)]
+                    .into_iter()
+                    .find(|&(limit, _)| f <= c(limit))
+            };
+            c(nice.map_or(10.0, |(_, n)| n)) * base
+        }
+        let measure = self.measure();
+        if !measure.is_finite() {
+            return None;
+        }
+        if measure.is_zero() {
+            return Some((Inclusive.between(*self.inf(), *self.sup()), measure));
+        }
+        let range = nice_num(measure, false);
+        let step = nice_num(range / T::from(target_ticks.max(2) - 1).unwrap(), true);
+        Some((self.snap_to_grid(step)?, step))
+    }
+
+    /// Smallest closed integer interval containing `self`.
+    /// Returns `None` if the boundaries cannot be cast to `U`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(-0.5).to(Exclusive.at(2.0)); // (-0.5, 2.0)
+    /// assert_eq!(a.round_outward::<i64>().unwrap(), Inclusive.at(-1).to(Inclusive.at(2)));
+    /// ```
+    pub fn round_outward<U: num::NumCast + PartialOrd>(&self) -> Option<Interval<U>> {
+        Interval::try_new(
+            Inclusive.at(num::cast(self.inf().floor())?),
+            Inclusive.at(num::cast(self.sup().ceil())?),
+        )
+    }
+
+    /// Largest closed integer interval contained in `self`.
+    /// Returns `None` if `self` contains no integer or the boundaries cannot be cast to `U`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(-1.0).to(Inclusive.at(2.5)); // (-1.0, 2.5]
+    /// assert_eq!(a.round_inward::<i64>().unwrap(), Inclusive.at(0).to(Inclusive.at(2)));
+    ///
+    /// let a = Inclusive.at(0.2).to(Inclusive.at(0.8));
+    /// assert!(a.round_inward::<i64>().is_none());
+    /// ```
+    pub fn round_inward<U: num::NumCast + PartialOrd>(&self) -> Option<Interval<U>>
+    where
+        Bound<T, L>: Ceil<T>,
+        Bound<T, R>: Floor<T>,
+    {
+        Interval::try_new(
+            Inclusive.at(num::cast(self.left.ceil())?),
+            Inclusive.at(num::cast(self.right.floor())?),
+        )
+    }
+
+    /// Half-open integer interval whose width is `measure()` rounded, placed around the center of `self`.
+    /// Unlike `round_outward()`, the widths do not drift when many intervals are rounded.
+    /// Returns `None` if the rounded width is zero or the boundaries cannot be cast to `U`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.3).to(Exclusive.at(1.2));
+    /// let b = Inclusive.at(1.2).to(Exclusive.at(2.1));
+    /// assert_eq!(a.round_preserving_width::<i64>().unwrap(), Inclusive.at(0).to(Exclusive.at(1)));
+    /// assert_eq!(b.round_preserving_width::<i64>().unwrap(), Inclusive.at(1).to(Exclusive.at(2)));
+    /// assert!(Inclusive.at(0.3).to(Exclusive.at(0.4)).round_preserving_width::<i64>().is_none());
+    /// ```
+    pub fn round_preserving_width<U: num::NumCast + PartialOrd>(
+        &self,
+    ) -> Option<Interval<U, Inclusive, Exclusive>> {
+        let width = self.measure().round();
+        let start = (self.center() - width / (T::one() + T::one())).round();
+        Interval::try_new(
+            Inclusive.at(num::cast(start)?),
+            Exclusive.at(num::cast(start + width)?),
+        )
+    }
+}
+
+impl<T: num::PrimInt, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
+where
+    Bound<T, L>: Ceil<T>,
+    Bound<T, R>: Floor<T>,
+{
+    /// Closed interval containing exactly the same integers as `self`.
+    /// Returns `None` if `self` contains no integer, e.g. `(3, 4)`.
+    /// This is lossless only because `T` is discrete.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(1).to(Exclusive.at(5)); // (1, 5)
+    /// assert_eq!(a.to_closed().unwrap(), Inclusive.at(2).to(Inclusive.at(4)));
+    /// assert!(Exclusive.at(3).to(Exclusive.at(4)).to_closed().is_none());
+    /// ```
+    pub fn to_closed(&self) -> Option<Interval<T>> {
+        Interval::try_new(self.left.ceil().into(), self.right.floor().into())
+    }
+
+    /// Half-open interval `[a, b)` containing exactly the same integers as `self`.
+    /// Returns `None` if `self` contains no integer or `b` overflows `T`.
+    /// This is lossless only because `T` is discrete.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(1).to(Inclusive.at(5)); // (1, 5]
+    /// assert_eq!(a.to_half_open().unwrap(), Inclusive.at(2).to(Exclusive.at(6)));
+    /// assert!(Inclusive.at(0).to(Inclusive.at(i32::MAX)).to_half_open().is_none());
+    /// ```
+    pub fn to_half_open(&self) -> Option<Interval<T, Inclusive, Exclusive>> {
+        let closed = self.to_closed()?;
+        let sup = closed.sup().checked_add(&T::one())?;
+        Interval::try_new(closed.left.0, Exclusive.at(sup))
+    }
+
+    /// Number of integers contained in `self`. Saturates at `usize::MAX`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// assert_eq!(Inclusive.at(0).to(Exclusive.at(5)).count(), 5);
+    /// assert_eq!(Exclusive.at(0).to(Exclusive.at(1)).count(), 0);
+    /// assert_eq!(Inclusive.at(i64::MIN).to(Inclusive.at(i64::MAX)).count(), usize::MAX);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.to_closed().map_or(0, |i| {
+            i.sup()
+                .checked_sub(i.inf())
+                .and_then(|d| d.to_usize())
+                .and_then(|d| d.checked_add(1))
+                .unwrap_or(usize::MAX)
+        })
+    }
+
+    /// Number of integers contained in both `self` and `other`. Saturates at `usize::MAX`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Inclusive.at(5));   // [0, 5]
+    /// let b = Exclusive.at(3).to(Exclusive.at(9));   // (3, 9)
+    /// assert_eq!(a.overlap_count(&a), 6);
+    /// assert_eq!(a.overlap_count(&Inclusive.at(3).to(Inclusive.at(9))), 3);
+    /// assert_eq!(b.overlap_count(&Exclusive.at(8).to(Exclusive.at(12))), 0);
+    /// ```
+    pub fn overlap_count(&self, other: &Self) -> usize {
+        self.intersection(other).map_or(0, |i| i.count())
+    }
+
+    /// Check if `self` and `other` contain exactly the same integers, regardless of their boundary types.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a: Interval<i32, BoundType> = Inclusive.at(0).to(Exclusive.at(3)).into(); // [0, 3)
+    /// let b: Interval<i32, BoundType> = Inclusive.between(0, 2).into();              // [0, 2]
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_as_set(&b));
+    /// assert!(a.eq_as_set(&Exclusive.at(-1).to(Inclusive.at(2))));
+    /// assert!(!a.eq_as_set(&Inclusive.between(0, 3)));
+    /// ```
+    pub fn eq_as_set<L2, R2>(&self, other: &Interval<T, L2, R2>) -> bool
+    where
+        L2: BoundaryOf<Left>,
+        R2: BoundaryOf<Right>,
+        Bound<T, L2>: Ceil<T>,
+        Bound<T, R2>: Floor<T>,
+    {
+        self.to_closed() == other.to_closed()
+    }
+
+    /// Integers strictly between `self` and `other` as a closed interval, i.e., `gap()` followed by `to_closed()`.
+    /// Returns `None` if no integer lies in the gap.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(9).to(Exclusive.at(12));  // [9, 12)
+    /// let b = Inclusive.at(15).to(Exclusive.at(17)); // [15, 17)
+    /// assert_eq!(a.gap(&b).unwrap(), Inclusive.at(12).to(Exclusive.at(15)));
+    /// assert_eq!(a.gap_closed(&b).unwrap(), Inclusive.between(12, 14));
+    /// assert_eq!(b.gap_closed(&a).unwrap(), Inclusive.between(12, 14));
+    ///
+    /// let c = Exclusive.at(0).to(Exclusive.at(3));  // (0, 3)
+    /// let d = Exclusive.at(4).to(Exclusive.at(9));  // (4, 9)
+    /// assert_eq!(c.gap_closed(&d).unwrap(), Inclusive.between(3, 4));
+    /// assert_eq!(c.gap_closed(&Exclusive.at(3).to(Exclusive.at(5))).unwrap(), Inclusive.between(3, 3));
+    ///
+    /// let e = Inclusive.between(0, 3);
+    /// assert!(e.gap(&Inclusive.between(4, 5)).is_some());       // (3, 4)
+    /// assert!(e.gap_closed(&Inclusive.between(4, 5)).is_none()); // contains no integer
+    /// ```
+    pub fn gap_closed(&self, other: &Self) -> Option<Interval<T>>
+    where
+        L: Flip,
+        R: Flip,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+        Bound<T, R::Flip>: Ceil<T>,
+        Bound<T, L::Flip>: Floor<T>,
+    {
+        self.gap(other)?.to_closed()
+    }
 }
 
 impl<T, L, R> Interval<T, L, R> {
@@ -566,6 +2031,155 @@ impl<T, L, R> Interval<T, L, R> {
             right: self.right.cast(),
         }
     }
+
+    /// Borrow the boundary values, like `Option::as_ref()`.
+    /// Intervals of references work like the original ones without moving or cloning large values,
+    /// and can be built from borrowed values directly by `Inclusive.at(&t)`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at("apple".to_string()).to(Exclusive.at("banana".to_string()));
+    /// let key = "avocado".to_string();
+    /// assert!(a.as_ref().contains(&&key));
+    ///
+    /// let (lo, hi) = ("b".to_string(), "c".to_string());
+    /// let b = Inclusive.at(&lo).to(Exclusive.at(&hi));
+    /// let span = a.as_ref().span(&b);
+    /// assert_eq!((*span.inf(), *span.sup()), (a.inf(), &hi));
+    /// ```
+    pub fn as_ref(&self) -> Interval<&T, L, R>
+    where
+        L: Copy,
+        R: Copy,
+    {
+        Interval {
+            left: self.left.as_ref(),
+            right: self.right.as_ref(),
+        }
+    }
+
+    /// Apply `f` to both boundaries keeping boundary types.
+    /// Returns `Err(IntervalIsEmpty)` if the result is empty, which may happen when `f` is not monotonically increasing.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let pixels = Inclusive.at(0).to(Exclusive.at(640));
+    /// let world = pixels.map(|x| x as f64 * 0.5).unwrap();
+    /// assert_eq!(world, Inclusive.at(0.0).to(Exclusive.at(320.0)));
+    ///
+    /// assert!(pixels.map(|x| -x).is_err()); // [0, -640) is empty.
+    /// ```
+    pub fn map<U: PartialOrd>(
+        self,
+        f: impl Fn(T) -> U,
+    ) -> Result<Interval<U, L, R>, IntervalIsEmpty>
+    where
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        Interval::new_(self.left.map(&f), self.right.map(&f)).ok_or(IntervalIsEmpty)
+    }
+
+    /// Fallible version of `map()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, TryMapError};
+    /// let a = Inclusive.at("0.5").to(Exclusive.at("2.5"));
+    /// let b = a.try_map(|s| s.parse::<f64>()).unwrap();
+    /// assert_eq!(b, Inclusive.at(0.5).to(Exclusive.at(2.5)));
+    ///
+    /// let a = Inclusive.at("0.5").to(Exclusive.at("x"));
+    /// assert!(matches!(a.try_map(|s| s.parse::<f64>()), Err(TryMapError::Map(_))));
+    ///
+    /// let a = Inclusive.at("0.5").to(Exclusive.at("1e-3")); // ["0.5", "1e-3") in lexicographic order
+    /// assert!(matches!(a.try_map(|s| s.parse::<f64>()), Err(TryMapError::IntervalIsEmpty(_))));
+    /// ```
+    pub fn try_map<U: PartialOrd, E>(
+        self,
+        f: impl Fn(T) -> Result<U, E>,
+    ) -> Result<Interval<U, L, R>, TryMapError<E>>
+    where
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        let left = Bound {
+            limit: f(self.left.0.limit).map_err(TryMapError::Map)?,
+            bound_type: self.left.0.bound_type,
+        };
+        let right = Bound {
+            limit: f(self.right.0.limit).map_err(TryMapError::Map)?,
+            bound_type: self.right.0.bound_type,
+        };
+        Ok(Interval::new_(left.into(), right.into()).ok_or(IntervalIsEmpty)?)
+    }
+
+    /// Convert the scalar type by `TryFrom`, e.g., narrowing `i64` to `i32`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, TryMapError};
+    /// let a = Inclusive.at(0_i64).to(Exclusive.at(100));
+    /// let b: Interval<i32, _, _> = a.try_convert().unwrap();
+    /// assert_eq!(b, Inclusive.at(0).to(Exclusive.at(100)));
+    ///
+    /// let a = Inclusive.at(0_i64).to(Exclusive.at(1 << 40));
+    /// assert!(matches!(a.try_convert::<i32>(), Err(TryMapError::Map(_))));
+    /// ```
+    pub fn try_convert<U: PartialOrd + TryFrom<T>>(
+        self,
+    ) -> Result<Interval<U, L, R>, TryMapError<U::Error>>
+    where
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        self.try_map(U::try_from)
+    }
+
+    /// Image of `self` under a strictly increasing function `f`, keeping boundary types.
+    /// Panics if the result is empty, which happens only if `f` is not strictly increasing on `self`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1.0_f64).to(Exclusive.at(std::f64::consts::E));
+    /// assert_eq!(a.map_monotonic(f64::ln), Inclusive.at(0.0).to(Exclusive.at(1.0)));
+    /// ```
+    pub fn map_monotonic<U: PartialOrd>(self, f: impl Fn(T) -> U) -> Interval<U, L, R>
+    where
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        self.map(f).expect("f must be strictly increasing")
+    }
+
+    /// Image of `self` under a strictly decreasing function `f`.
+    /// The boundaries are swapped, so the left boundary type of the result is `R` and the right one is `L`.
+    /// Panics if the result is empty, which happens only if `f` is not strictly decreasing on `self`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1.0).to(Exclusive.at(4.0));     // [1, 4)
+    /// assert_eq!(a.map_antitone(|x| 1.0 / x), Exclusive.at(0.25).to(Inclusive.at(1.0))); // (1/4, 1]
+    /// ```
+    pub fn map_antitone<U: PartialOrd>(self, f: impl Fn(T) -> U) -> Interval<U, R, L>
+    where
+        L: BoundaryOf<Right>,
+        R: BoundaryOf<Left>,
+    {
+        let left = self.right.0.map(&f);
+        let right = self.left.0.map(&f);
+        Interval::new_(left.into(), right.into()).expect("f must be strictly decreasing")
+    }
+
+    /// Mirror image of `self` across `pivot`, i.e., `map_antitone(|x| 2 * pivot - x)`.
+    /// The boundary types are swapped as well as the boundaries.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1.0).to(Exclusive.at(4.0));    // [1, 4)
+    /// assert_eq!(a.reflect(0.0), Exclusive.at(-4.0).to(Inclusive.at(-1.0))); // (-4, -1]
+    /// assert_eq!(a.reflect(5.0), Exclusive.at(6.0).to(Inclusive.at(9.0)));   // (6, 9]
+    /// assert_eq!(a.reflect(2.0).reflect(2.0), a);
+    /// ```
+    pub fn reflect(self, pivot: T) -> Interval<T, R, L>
+    where
+        T: PartialOrd + Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+        L: BoundaryOf<Right>,
+        R: BoundaryOf<Left>,
+    {
+        self.map_antitone(|x| pivot.clone() + pivot.clone() - x)
+    }
 }
 
 impl<T: num::NumCast, L, R> Interval<T, L, R> {
@@ -614,12 +2228,12 @@ impl<T, L: IntoGeneral, R: IntoGeneral> IntoGeneral for Interval<T, L, R> {
 /// ```
 impl<T, L, R> IntoIterator for Interval<T, L, R>
 where
-    std::ops::RangeInclusive<T>: Iterator<Item = T>,
+    core::ops::RangeInclusive<T>: Iterator<Item = T>,
     Bound<T, L>: Ceil<T>,
     Bound<T, R>: Floor<T>,
 {
     type Item = T;
-    type IntoIter = std::ops::RangeInclusive<T>;
+    type IntoIter = core::ops::RangeInclusive<T>;
     fn into_iter(self) -> Self::IntoIter {
         self.left().ceil()..=self.right().floor()
     }
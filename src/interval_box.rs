@@ -1,19 +1,19 @@
 use crate::bound_type::{Left, Right};
 use crate::ndim::NDim;
-use crate::traits::BoundaryOf;
-use crate::{Bound, Exclusive, Inclusive, Interval};
+use crate::traits::{BoundaryOf, Ceil, Floor};
+use crate::{Bound, Exclusive, Inclusive, Interval, IntervalIsEmpty};
 
 /// n-dimensional axis-aligned box as a cartesian product set of intervals, i.g., *[a, b)^n*.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BoxN<const N: usize, T, L = Inclusive, R = L>(NDim<N, Interval<T, L, R>>);
 
-impl<const N: usize, T, L, R> std::ops::Deref for BoxN<N, T, L, R> {
+impl<const N: usize, T, L, R> core::ops::Deref for BoxN<N, T, L, R> {
     type Target = NDim<N, Interval<T, L, R>>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<const N: usize, T, L, R> std::ops::DerefMut for BoxN<N, T, L, R> {
+impl<const N: usize, T, L, R> core::ops::DerefMut for BoxN<N, T, L, R> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -44,6 +44,53 @@ impl<const N: usize, T, L, R> BoxN<N, T, L, R> {
     pub fn into_array(self) -> [Interval<T, L, R>; N] {
         self.into()
     }
+
+    /// Construct from per-axis std ranges.
+    /// Returns `Err(IntervalIsEmpty)` if any of the ranges is empty.
+    /// ```
+    /// use inter_val::{BoxN, Inclusive, Exclusive};
+    /// let a: BoxN<2, f64, Inclusive, Exclusive> = BoxN::from_ranges([0.0..1.0, 2.0..3.0]).unwrap();
+    /// assert_eq!(a.inf(), [0.0, 2.0]);
+    /// assert!(BoxN::<2, i32>::from_ranges([0..=1, 3..=2]).is_err());
+    /// ```
+    pub fn from_ranges<Rg>(ranges: [Rg; N]) -> Result<Self, IntervalIsEmpty>
+    where
+        Rg: TryInto<Interval<T, L, R>, Error = IntervalIsEmpty>,
+    {
+        let tmp = ranges.map(|r| r.try_into().ok());
+        if tmp.iter().any(Option::is_none) {
+            return Err(IntervalIsEmpty);
+        }
+        Ok(tmp.map(Option::unwrap).into())
+    }
+
+    /// Lower-dimensional box consisting of the intervals on the specified `axes`. Panics if an axis is out of range.
+    /// ```
+    /// use inter_val::{Box2, Box3};
+    /// let a: Box3<i32> = Box3::between(&[0, 10, 20], &[1, 11, 21]);
+    /// let b: Box2<i32> = a.project([2, 0]);
+    /// assert_eq!(b, Box2::between(&[20, 0], &[21, 1]));
+    /// ```
+    pub fn project<const M: usize>(&self, axes: [usize; M]) -> BoxN<M, T, L, R>
+    where
+        Interval<T, L, R>: Clone,
+    {
+        axes.map(|i| self[i].clone()).into()
+    }
+}
+
+/// Construct a `BoxN` from std ranges, returning `Result<BoxN<..>, IntervalIsEmpty>`.
+/// ```
+/// use inter_val::{box_n, BoxN, Inclusive, Exclusive};
+/// let a: BoxN<3, f64, Inclusive, Exclusive> = box_n![0.0..1.0, 2.0..3.0, -1.0..1.0].unwrap();
+/// assert_eq!(a.sup(), [1.0, 3.0, 1.0]);
+/// assert!(box_n![0..=1, 5..=4].is_err());
+/// ```
+#[macro_export]
+macro_rules! box_n {
+    ($($range:expr),+ $(,)?) => {
+        $crate::BoxN::from_ranges([$($range),+])
+    };
 }
 
 impl<T, L, R> BoxN<2, T, L, R> {
@@ -55,6 +102,33 @@ impl<T, L, R> BoxN<3, T, L, R> {
     pub fn new(x: Interval<T, L, R>, y: Interval<T, L, R>, z: Interval<T, L, R>) -> Self {
         Self([x, y, z].into())
     }
+
+    /// Projection onto the *xy* plane.
+    /// ```
+    /// use inter_val::{Box2, Box3};
+    /// let a: Box3<f64> = Box3::between(&[0.0, 1.0, 2.0], &[3.0, 4.0, 5.0]);
+    /// assert_eq!(a.xy(), Box2::between(&[0.0, 1.0], &[3.0, 4.0]));
+    /// assert_eq!(a.xz(), Box2::between(&[0.0, 2.0], &[3.0, 5.0]));
+    /// assert_eq!(a.yz(), Box2::between(&[1.0, 2.0], &[4.0, 5.0]));
+    /// ```
+    pub fn xy(&self) -> BoxN<2, T, L, R>
+    where
+        Interval<T, L, R>: Clone,
+    {
+        self.project([0, 1])
+    }
+    pub fn xz(&self) -> BoxN<2, T, L, R>
+    where
+        Interval<T, L, R>: Clone,
+    {
+        self.project([0, 2])
+    }
+    pub fn yz(&self) -> BoxN<2, T, L, R>
+    where
+        Interval<T, L, R>: Clone,
+    {
+        self.project([1, 2])
+    }
 }
 impl<T, L, R> BoxN<4, T, L, R> {
     pub fn new(
@@ -83,10 +157,10 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
         T: Into<Bound<T, L>> + Into<Bound<T, R>>,
     {
         let mut tmp: [_; N] =
-            std::array::from_fn(|i| Interval::try_between(a[i].clone(), b[i].clone()));
+            core::array::from_fn(|i| Interval::try_between(a[i].clone(), b[i].clone()));
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
     }
 
     /// ```
@@ -98,15 +172,15 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     where
         T: Into<Bound<T, L>> + Into<Bound<T, R>>,
     {
-        std::array::from_fn(|i| Interval::between(a[i].clone(), b[i].clone())).into()
+        core::array::from_fn(|i| Interval::between(a[i].clone(), b[i].clone())).into()
     }
 
     pub fn inf(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].inf().clone()).into()
+        core::array::from_fn(|i| self[i].inf().clone()).into()
     }
 
     pub fn sup(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].sup().clone()).into()
+        core::array::from_fn(|i| self[i].sup().clone()).into()
     }
 
     pub fn contains(&self, t: &[T; N]) -> bool {
@@ -117,37 +191,76 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
         self.iter().zip(other.iter()).all(|(i, o)| i.includes(o))
     }
 
+    /// Nearest point of the closure of `self` to `p`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 10]);
+    /// assert_eq!(a.closest_point(&[-3, 4]), [0, 4]);
+    /// assert_eq!(a.closest_point(&[5, 5]), [5, 5]);
+    /// ```
+    pub fn closest_point(&self, p: &[T; N]) -> NDim<N, T> {
+        core::array::from_fn(|i| self[i].clamp(p[i].clone())).into()
+    }
+
+    /// All `2^N` corners of the closure of `self`.
+    /// The `k`-th corner takes `sup` on the axis `i` if the `i`-th bit of `k` is set, and `inf` otherwise.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 1.0]);
+    /// let corners: Vec<_> = a.corners().collect();
+    /// assert_eq!(corners, vec![[0.0, 0.0], [2.0, 0.0], [0.0, 1.0], [2.0, 1.0]]);
+    /// ```
+    pub fn corners(&self) -> impl Iterator<Item = NDim<N, T>> + '_ {
+        (0..1_usize << N).map(move |k| {
+            core::array::from_fn(|i| {
+                if k & (1 << i) == 0 {
+                    self[i].inf().clone()
+                } else {
+                    self[i].sup().clone()
+                }
+            })
+            .into()
+        })
+    }
+
     pub fn overlaps(&self, other: &Self) -> bool {
         self.iter().zip(other.iter()).all(|(i, j)| i.overlaps(j))
     }
 
     pub fn closure(&self) -> BoxN<N, T, Inclusive> {
-        std::array::from_fn(|i| self[i].clone().closure()).into()
+        core::array::from_fn(|i| self[i].clone().closure()).into()
     }
 
     pub fn interior(&self) -> Option<BoxN<N, T, Exclusive>> {
-        let mut tmp: [_; N] = std::array::from_fn(|i| self[i].clone().interior());
+        let mut tmp: [_; N] = core::array::from_fn(|i| self[i].clone().interior());
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
     }
 
     pub fn intersection(&self, other: &Self) -> Option<Self> {
-        let mut tmp: [_; N] = std::array::from_fn(|i| self[i].intersection(&other[i]));
+        let mut tmp: [_; N] = core::array::from_fn(|i| self[i].intersection(&other[i]));
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
     }
 
+    /// Smallest box containing both `self` and `other`.
+    /// ```
+    /// use inter_val::Box3;
+    /// let a: Box3<f64> = Box3::between(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0]);
+    /// let b: Box3<f64> = Box3::between(&[0.5, -2.0, 3.0], &[1.5, 0.5, 4.0]);
+    /// assert_eq!(a.span(&b), Box3::between(&[0.0, -2.0, 0.0], &[1.5, 1.0, 4.0]));
+    /// ```
     pub fn span(&self, other: &Self) -> Self {
-        std::array::from_fn(|i| self[i].clone().span(&other[i])).into()
+        core::array::from_fn(|i| self[i].clone().span(&other[i])).into()
     }
 
     pub fn dilate(&self, delta: T) -> Self
     where
-        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+        T: core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
     {
-        std::array::from_fn(|i| self[i].clone().dilate(delta.clone())).into()
+        core::array::from_fn(|i| self[i].clone().dilate(delta.clone())).into()
     }
 
     /// ```
@@ -157,7 +270,7 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     /// assert_eq!(b, Box2::between(&[0, 0], &[20, 10]));
     /// ```
     pub fn hull(self, p: &[T; N]) -> Self {
-        std::array::from_fn(|i| self[i].clone().hull(p[i].clone())).into()
+        core::array::from_fn(|i| self[i].clone().hull(p[i].clone())).into()
     }
 
     pub fn span_many<A: Into<Self>>(items: impl IntoIterator<Item = A>) -> Option<Self> {
@@ -166,6 +279,20 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
         Some(items.fold(first, |acc, item| acc.span(&item.into())))
     }
 
+    /// Bounding box of points. Returns `None` if `items` is empty.
+    /// ```
+    /// use inter_val::{Box2, NDim};
+    /// let points = [[1, 5], [3, 2], [-1, 4]];
+    /// let a: Box2<i32> = Box2::hull_many(&points).unwrap();
+    /// assert_eq!(a, Box2::between(&[-1, 2], &[3, 5]));
+    ///
+    /// // Points given as `NDim`
+    /// let points = [NDim::<2, _>::new(1, 5), NDim::<2, _>::new(3, 2)];
+    /// let a: Box2<i32> = Box2::hull_many(points.iter().map(NDim::as_array)).unwrap();
+    /// assert_eq!(a, Box2::between(&[1, 2], &[3, 5]));
+    ///
+    /// assert!(Box2::<i32>::hull_many(&[]).is_none());
+    /// ```
     pub fn hull_many<'a>(items: impl IntoIterator<Item = &'a [T; N]>) -> Option<Self>
     where
         T: Clone + Into<Bound<T, L>> + Into<Bound<T, R>> + 'a,
@@ -192,18 +319,155 @@ where
     L: BoundaryOf<Left>,
     R: BoundaryOf<Right>,
 {
+    pub fn center(&self) -> NDim<N, T> {
+        core::array::from_fn(|i| self[i].center()).into()
+    }
     pub fn size(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].measure()).into()
+        core::array::from_fn(|i| self[i].measure()).into()
+    }
+
+    /// Half of `size()`, i.e., per-axis distance from `center()` to the boundaries.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 1.0], &[4.0, 2.0]);
+    /// assert_eq!(a.center(), [2.0, 1.5]);
+    /// assert_eq!(a.half_extents(), [2.0, 0.5]);
+    /// ```
+    pub fn half_extents(&self) -> NDim<N, T> {
+        core::array::from_fn(|i| self[i].measure() / (T::one() + T::one())).into()
     }
     pub fn measure(&self) -> T {
         self.iter()
             .map(|item| item.measure())
             .fold(T::one(), |a, b| a * b)
     }
+
+    /// Index of the axis with the largest `measure()`. The first one wins on ties.
+    /// Returns `None` only if `N == 0`.
+    /// ```
+    /// use inter_val::Box3;
+    /// let a: Box3<f64> = Box3::between(&[0.0, 0.0, 0.0], &[1.0, 3.0, 2.0]);
+    /// assert_eq!(a.longest_axis(), Some(1));
+    /// assert_eq!(a.shortest_axis(), Some(0));
+    /// ```
+    pub fn longest_axis(&self) -> Option<usize> {
+        (0..N).reduce(|k, i| {
+            if self[k].measure() < self[i].measure() {
+                i
+            } else {
+                k
+            }
+        })
+    }
+
+    /// Index of the axis with the smallest `measure()`. The first one wins on ties.
+    /// Returns `None` only if `N == 0`.
+    pub fn shortest_axis(&self) -> Option<usize> {
+        (0..N).reduce(|k, i| {
+            if self[i].measure() < self[k].measure() {
+                i
+            } else {
+                k
+            }
+        })
+    }
+}
+
+impl<const N: usize, T: num::PrimInt, L: BoundaryOf<Left>, R: BoundaryOf<Right>> BoxN<N, T, L, R>
+where
+    Bound<T, L>: Ceil<T>,
+    Bound<T, R>: Floor<T>,
+{
+    /// Iterate over every integer point in `self` in row-major order, i.e., the last axis varies fastest.
+    /// ```
+    /// use inter_val::{Box2, Exclusive};
+    /// let a: Box2<i64, Exclusive> = Box2::between(&[0, 0], &[3, 4]);  // (0, 3) x (0, 4)
+    /// let points: Vec<_> = a.iter_points().map(|p| p.into_array()).collect();
+    /// assert_eq!(points, vec![[1, 1], [1, 2], [1, 3], [2, 1], [2, 2], [2, 3]]);
+    ///
+    /// let b: Box2<i64, Exclusive> = Box2::between(&[0, 0], &[1, 4]);
+    /// assert_eq!(b.iter_points().count(), 0);
+    /// ```
+    pub fn iter_points(&self) -> impl Iterator<Item = NDim<N, T>> {
+        let lo: [T; N] = core::array::from_fn(|i| self[i].left().ceil());
+        let hi: [T; N] = core::array::from_fn(|i| self[i].right().floor());
+        let first = (0..N).all(|i| lo[i] <= hi[i]).then_some(lo);
+        core::iter::successors(first, move |cur| {
+            let mut next = *cur;
+            for i in (0..N).rev() {
+                if next[i] < hi[i] {
+                    next[i] = next[i] + T::one();
+                    return Some(next);
+                }
+                next[i] = lo[i];
+            }
+            None
+        })
+        .map(NDim::from)
+    }
 }
 
 impl<const N: usize, T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> BoxN<N, T, L, R> {
-    pub fn center(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].center()).into()
+    /// Euclidean length of the diagonal.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[3.0, 4.0]);
+    /// assert_eq!(a.diagonal_length(), 5.0);
+    /// ```
+    pub fn diagonal_length(&self) -> T {
+        self.iter()
+            .map(|item| item.measure().powi(2))
+            .fold(T::zero(), |a, b| a + b)
+            .sqrt()
+    }
+
+    /// Euclidean distance from `p` to `closest_point(p)`, which is zero if `p` is in the closure of `self`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[1.0, 1.0]);
+    /// assert_eq!(a.distance_to_point(&[4.0, 5.0]), 5.0);
+    /// assert_eq!(a.distance_to_point(&[0.5, 0.5]), 0.0);
+    /// ```
+    pub fn distance_to_point(&self, p: &[T; N]) -> T {
+        self.closest_point(p)
+            .iter()
+            .zip(p.iter())
+            .map(|(c, p)| (*c - *p).powi(2))
+            .fold(T::zero(), |a, b| a + b)
+            .sqrt()
+    }
+}
+
+impl<const N: usize, T, L, R> BoxN<N, T, L, R>
+where
+    Interval<T, L, R>: Clone + core::ops::Add<Output = Interval<T, L, R>>,
+{
+    /// Minkowski sum, i.e., interval addition on each axis.
+    /// ```
+    /// use inter_val::Box2;
+    /// let obstacle: Box2<f64> = Box2::between(&[10.0, 10.0], &[20.0, 15.0]);
+    /// let robot: Box2<f64> = Box2::between(&[-1.0, -2.0], &[1.0, 2.0]);
+    /// assert_eq!(obstacle.minkowski_sum(&robot), Box2::between(&[9.0, 8.0], &[21.0, 17.0]));
+    /// ```
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].clone() + other[i].clone()).into()
+    }
+}
+
+impl<const N: usize, T, L, R> BoxN<N, T, L, R>
+where
+    Interval<T, L, R>: Clone + core::ops::Sub<Output = Interval<T, L, R>>,
+{
+    /// Minkowski difference *A ⊕ (-B)*, i.e., interval subtraction on each axis.
+    /// `self` and `other` overlap iff the result contains the origin.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 2.0]);
+    /// let b: Box2<f64> = Box2::between(&[1.0, 3.0], &[4.0, 5.0]);
+    /// assert_eq!(a.minkowski_difference(&b), Box2::between(&[-4.0, -5.0], &[1.0, -1.0]));
+    /// assert!(!a.minkowski_difference(&b).contains(&[0.0, 0.0]));
+    /// ```
+    pub fn minkowski_difference(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].clone() - other[i].clone()).into()
     }
 }
@@ -0,0 +1,126 @@
+use crate::{Exclusive, Inclusive, Interval};
+use alloc::vec::Vec;
+
+/// Map from disjoint half-open intervals *[a, b)* to values.
+/// Inserting an interval overlapping with existing ones splits them at the boundaries,
+/// and the values on the overlapped segments are combined by a user-supplied function.
+/// ```
+/// use inter_val::{IntervalMap, Inclusive, Exclusive};
+/// let mut depth = IntervalMap::new();
+/// for read in [(0, 10), (5, 15), (8, 12)] {
+///     depth.insert_with(Inclusive.at(read.0).to(Exclusive.at(read.1)), 1, |a, b| a + b);
+/// }
+/// let segments: Vec<_> = depth.iter().map(|(i, v)| (*i.inf(), *i.sup(), *v)).collect();
+/// assert_eq!(segments, vec![(0, 5, 1), (5, 8, 2), (8, 10, 3), (10, 12, 2), (12, 15, 1)]);
+/// assert_eq!(depth.get(&9), Some(&3));
+/// assert_eq!(depth.get(&15), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<T, V> {
+    segments: Vec<(Interval<T, Inclusive, Exclusive>, V)>,
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl<T, V> IntervalMap<T, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Iterate over the segments in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T, Inclusive, Exclusive>, &V)> {
+        self.segments.iter().map(|(i, v)| (i, v))
+    }
+}
+
+impl<T: PartialOrd, V> IntervalMap<T, V> {
+    /// The value of the segment containing `t`, found by binary search.
+    pub fn get(&self, t: &T) -> Option<&V> {
+        let i = self.segments.partition_point(|(i, _)| i.sup() <= t);
+        self.segments
+            .get(i)
+            .filter(|(i, _)| i.contains(t))
+            .map(|(_, v)| v)
+    }
+}
+
+impl<T: PartialOrd + Clone, V: Clone> IntervalMap<T, V> {
+    /// Insert `value` on `interval`. Where it overlaps with an existing segment of value `old`,
+    /// the value becomes `merge(old, value)`.
+    /// ```
+    /// use inter_val::{IntervalMap, Inclusive, Exclusive};
+    /// let mut map = IntervalMap::new();
+    /// assert!(map.is_empty() && map.get(&0).is_none());
+    ///
+    /// map.insert_with(Inclusive.at(0).to(Exclusive.at(3)), 1, |a, b| a + b);
+    /// map.insert_with(Inclusive.at(3).to(Exclusive.at(5)), 2, |a, b| a + b);  // adjacent
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!((map.get(&2), map.get(&3), map.get(&5)), (Some(&1), Some(&2), None));
+    ///
+    /// map.insert_with(Inclusive.at(2).to(Exclusive.at(3)), 10, |a, b| a + b);  // {2}
+    /// assert_eq!((map.get(&1), map.get(&2), map.get(&3)), (Some(&1), Some(&11), Some(&2)));
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn insert_with(
+        &mut self,
+        interval: Interval<T, Inclusive, Exclusive>,
+        value: V,
+        merge: impl Fn(V, V) -> V,
+    ) {
+        let (a, b) = (interval.inf().clone(), interval.sup().clone());
+        let start = self.segments.partition_point(|(i, _)| *i.sup() <= a);
+        let end = start + self.segments[start..].partition_point(|(i, _)| *i.inf() < b);
+
+        let mut pieces = Vec::new();
+        let mut push = |lower: &T, upper: &T, v: V| {
+            if let Some(i) =
+                Interval::try_new(Inclusive.at(lower.clone()), Exclusive.at(upper.clone()))
+            {
+                pieces.push((i, v));
+            }
+        };
+        let mut cursor = a.clone();
+        for (segment, old) in self.segments.drain(start..end) {
+            let (lower, upper) = (segment.inf(), segment.sup());
+            push(lower, &a, old.clone());
+            push(&cursor, lower, value.clone());
+            let overlap_lower = if *lower < a { &a } else { lower };
+            let overlap_upper = if b < *upper { &b } else { upper };
+            push(
+                overlap_lower,
+                overlap_upper,
+                merge(old.clone(), value.clone()),
+            );
+            push(&b, upper, old);
+            cursor = overlap_upper.clone();
+        }
+        push(&cursor, &b, value);
+        self.segments.splice(start..start, pieces);
+    }
+
+    /// Insert `value` on `interval`, overwriting the existing values there.
+    /// ```
+    /// use inter_val::{IntervalMap, Inclusive, Exclusive};
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Inclusive.at(0.0).to(Exclusive.at(10.0)), "a");
+    /// map.insert(Inclusive.at(3.0).to(Exclusive.at(5.0)), "b");
+    /// assert_eq!(map.get(&2.0), Some(&"a"));
+    /// assert_eq!(map.get(&3.0), Some(&"b"));
+    /// assert_eq!(map.get(&5.0), Some(&"a"));
+    /// ```
+    pub fn insert(&mut self, interval: Interval<T, Inclusive, Exclusive>, value: V) {
+        self.insert_with(interval, value, |_, new| new)
+    }
+}
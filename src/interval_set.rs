@@ -0,0 +1,230 @@
+use crate::bound_type::{Left, Right};
+use crate::half::{partial_max, partial_min};
+use crate::traits::{BoundaryOf, Flip};
+use crate::{Inclusive, Interval};
+use alloc::vec::Vec;
+
+/// Set of real numbers represented by sorted, disjoint intervals.
+/// Overlapping or adjacent intervals are merged on insertion.
+/// ```
+/// use inter_val::{IntervalSet, Inclusive, Exclusive};
+/// let mut set: IntervalSet<_, _, _> = [
+///     Inclusive.at(0).to(Exclusive.at(10)),
+///     Inclusive.at(20).to(Exclusive.at(30)),
+///     Inclusive.at(5).to(Exclusive.at(12)),
+/// ]
+/// .into_iter()
+/// .collect();
+/// set.insert(Inclusive.at(12).to(Exclusive.at(15)));  // adjacent to [0, 12)
+/// assert_eq!(set.len(), 2);
+/// assert_eq!(set.enclosing(&13), Some(&Inclusive.at(0).to(Exclusive.at(15))));
+/// assert_eq!(set.enclosing(&20), Some(&Inclusive.at(20).to(Exclusive.at(30))));
+/// assert_eq!(set.enclosing(&15), None);
+/// assert!(set.contains(&29) && !set.contains(&30));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T, L = Inclusive, R = L> {
+    items: Vec<Interval<T, L, R>>,
+}
+
+impl<T, L, R> Default for IntervalSet<T, L, R> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over the disjoint intervals in ascending order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Interval<T, L, R>> {
+        self.items.iter()
+    }
+
+    pub fn as_slice(&self) -> &[Interval<T, L, R>] {
+        &self.items
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    L: Flip,
+    R: Flip,
+    L::Flip: BoundaryOf<Right>,
+    R::Flip: BoundaryOf<Left>,
+{
+    /// Sort `items` and merge them by `Interval::coalesce()`.
+    pub fn new(items: impl IntoIterator<Item = Interval<T, L, R>>) -> Self {
+        let mut items: Vec<_> = items.into_iter().collect();
+        items.sort_by(|a, b| {
+            a.left()
+                .partial_cmp(b.left())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        Self {
+            items: Interval::coalesce(items).collect(),
+        }
+    }
+
+    /// Add `interval`, merging the intervals which overlap with or are adjacent to it.
+    /// ```
+    /// use inter_val::{BoundType, Interval, IntervalSet, Inclusive, Exclusive};
+    /// let mut set = IntervalSet::<i32, BoundType>::default();
+    /// assert!(set.is_empty() && !set.contains(&0));
+    ///
+    /// set.insert(Inclusive.between(5, 5).into());  // [5, 5]
+    /// assert!(set.contains(&5) && !set.contains(&4) && !set.contains(&6));
+    ///
+    /// set.insert(Inclusive.at(0).to(Exclusive.at(1)).into());  // [0, 1)
+    /// set.insert(Exclusive.at(1).to(Inclusive.at(2)).into());  // (1, 2]: gap at 1
+    /// assert_eq!(set.len(), 3);
+    /// assert!(set.contains(&0) && !set.contains(&1) && set.contains(&2));
+    ///
+    /// set.insert(Inclusive.between(1, 1).into());  // fills the gap
+    /// assert_eq!(set.as_slice(), &[Inclusive.between(0, 2).into(), Inclusive.between(5, 5).into()]);
+    ///
+    /// set.insert(Exclusive.between(2, 5).into());  // adjacent to both
+    /// assert_eq!(set.as_slice(), &[Interval::from(Inclusive.between(0, 5))]);
+    /// ```
+    pub fn insert(&mut self, interval: Interval<T, L, R>) {
+        let start = self
+            .items
+            .partition_point(|x| x.gap(&interval).is_some() && x.left() < interval.left());
+        let end = start + self.items[start..].partition_point(|x| x.gap(&interval).is_none());
+        let merged = self.items[start..end]
+            .iter()
+            .fold(interval, |acc, x| acc.span(x));
+        self.items.splice(start..end, [merged]);
+    }
+
+    /// The interval containing `t`, found by binary search.
+    pub fn enclosing(&self, t: &T) -> Option<&Interval<T, L, R>> {
+        let i = self.items.partition_point(|x| !x.right().contains(t));
+        self.items.get(i).filter(|x| x.contains(t))
+    }
+
+    pub fn contains(&self, t: &T) -> bool {
+        self.enclosing(t).is_some()
+    }
+
+    /// All pairs of overlapping intervals from `self` and `other`, found by merge-joining in *O(m + n)*.
+    /// ```
+    /// use inter_val::{IntervalSet, Inclusive, Exclusive};
+    /// let exons: IntervalSet<_, _, _> = [
+    ///     Inclusive.at(0).to(Exclusive.at(10)),
+    ///     Inclusive.at(20).to(Exclusive.at(30)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let reads: IntervalSet<_, _, _> = [
+    ///     Inclusive.at(5).to(Exclusive.at(8)),
+    ///     Inclusive.at(9).to(Exclusive.at(22)),
+    ///     Inclusive.at(30).to(Exclusive.at(40)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let pairs: Vec<_> = exons.overlapping_pairs(&reads).map(|(a, b)| (*a.inf(), *b.inf())).collect();
+    /// assert_eq!(pairs, vec![(0, 5), (0, 9), (20, 9)]);
+    /// ```
+    pub fn overlapping_pairs<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = (&'a Interval<T, L, R>, &'a Interval<T, L, R>)> + 'a {
+        let (mut a, mut b) = (self.items.iter().peekable(), other.items.iter().peekable());
+        core::iter::from_fn(move || loop {
+            let (x, y) = (*a.peek()?, *b.peek()?);
+            if x.right() <= y.right() {
+                a.next();
+            } else {
+                b.next();
+            }
+            if x.overlaps(y) {
+                return Some((x, y));
+            }
+        })
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left> + Flip<Flip = R>,
+    R: BoundaryOf<Right> + Flip<Flip = L>,
+{
+    /// Complement of `self` within `universe`, e.g., the allowed ranges given the forbidden ones.
+    /// Defined only for `IntervalSet<T, Inclusive, Exclusive>`, `IntervalSet<T, Exclusive, Inclusive>`, and `IntervalSet<T, BoundType>`
+    /// like `Interval::difference()`.
+    /// ```
+    /// use inter_val::{IntervalSet, Inclusive, Exclusive};
+    /// let forbidden: IntervalSet<_, _, _> = [
+    ///     Inclusive.at(-5).to(Exclusive.at(2)),
+    ///     Inclusive.at(4).to(Exclusive.at(6)),
+    ///     Inclusive.at(9).to(Exclusive.at(20)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let allowed = forbidden.complement_within(&Inclusive.at(0).to(Exclusive.at(12)));
+    /// assert_eq!(allowed.as_slice(), &[
+    ///     Inclusive.at(2).to(Exclusive.at(4)),
+    ///     Inclusive.at(6).to(Exclusive.at(9)),
+    /// ]);
+    ///
+    /// let universe = Inclusive.at(0).to(Exclusive.at(12));
+    /// assert_eq!(IntervalSet::default().complement_within(&universe).as_slice(), &[universe]);
+    /// assert!(IntervalSet::from_iter([universe]).complement_within(&universe).is_empty());
+    /// ```
+    pub fn complement_within(&self, universe: &Interval<T, L, R>) -> Self {
+        let mut items = Vec::new();
+        let mut cursor = universe.left().clone();
+        for item in &self.items {
+            let right = partial_min(item.left().clone().flip(), universe.right().clone());
+            if let Some(piece) = Interval::try_new(cursor.0.clone(), right.0) {
+                items.push(piece);
+            }
+            cursor = partial_max(cursor, item.right().clone().flip());
+        }
+        if let Some(piece) = Interval::try_new(cursor.0, universe.right().0.clone()) {
+            items.push(piece);
+        }
+        Self { items }
+    }
+}
+
+impl<T, L, R> FromIterator<Interval<T, L, R>> for IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    L: Flip,
+    R: Flip,
+    L::Flip: BoundaryOf<Right>,
+    R::Flip: BoundaryOf<Left>,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T, L, R>>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<'a, T, L, R> IntoIterator for &'a IntervalSet<T, L, R> {
+    type Item = &'a Interval<T, L, R>;
+    type IntoIter = core::slice::Iter<'a, Interval<T, L, R>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T, L, R> IntoIterator for IntervalSet<T, L, R> {
+    type Item = Interval<T, L, R>;
+    type IntoIter = alloc::vec::IntoIter<Interval<T, L, R>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
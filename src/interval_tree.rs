@@ -0,0 +1,139 @@
+use crate::bound_type::{Left, Right};
+use crate::interval::is_valid_interval;
+use crate::traits::BoundaryOf;
+use crate::{Inclusive, Interval, LeftBounded, RightBounded};
+use alloc::vec::Vec;
+
+/// Static interval tree which answers stabbing and overlap queries in *O(log n + k)*.
+///
+/// The intervals are sorted by their left boundaries and regarded as an implicit balanced binary search tree,
+/// whose each node holds the maximum right boundary in its subtree.
+/// ```
+/// use inter_val::{Inclusive, Exclusive, IntervalTree};
+/// let tree = IntervalTree::new([
+///     (Inclusive.at(0).to(Exclusive.at(10)), "a"),
+///     (Inclusive.at(5).to(Exclusive.at(15)), "b"),
+///     (Inclusive.at(20).to(Exclusive.at(30)), "c"),
+/// ]);
+/// assert_eq!(tree.query_point(&7).collect::<Vec<_>>(), vec![&"a", &"b"]);
+/// assert_eq!(tree.query_point(&15).count(), 0);
+///
+/// let q = Inclusive.at(12).to(Exclusive.at(25));
+/// assert_eq!(tree.query_overlap(&q).collect::<Vec<_>>(), vec![&"b", &"c"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T, V, L = Inclusive, R = L> {
+    items: Vec<(Interval<T, L, R>, V)>,
+    max_right: Vec<RightBounded<T, R>>,
+}
+
+impl<T, V, L, R> IntervalTree<T, V, L, R> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over the stored items in ascending order of the left boundaries.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T, L, R>, &V)> {
+        self.items.iter().map(|(i, v)| (i, v))
+    }
+}
+
+impl<T: PartialOrd + Clone, V, L: BoundaryOf<Left>, R: BoundaryOf<Right>> IntervalTree<T, V, L, R> {
+    pub fn new(items: impl IntoIterator<Item = (Interval<T, L, R>, V)>) -> Self {
+        let mut items: Vec<_> = items.into_iter().collect();
+        items.sort_by(|(a, _), (b, _)| {
+            a.left()
+                .partial_cmp(b.left())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        let mut max_right: Vec<_> = items.iter().map(|(i, _)| i.right().clone()).collect();
+        Self::build(&mut max_right, 0, items.len());
+        Self { items, max_right }
+    }
+
+    fn build(max_right: &mut [RightBounded<T, R>], lo: usize, hi: usize) -> Option<usize> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = (lo + hi) / 2;
+        for child in [
+            Self::build(max_right, lo, mid),
+            Self::build(max_right, mid + 1, hi),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if max_right[mid] < max_right[child] {
+                max_right[mid] = max_right[child].clone();
+            }
+        }
+        Some(mid)
+    }
+
+    /// Values of the intervals which contain `t`, in ascending order of the left boundaries.
+    pub fn query_point(&self, t: &T) -> alloc::vec::IntoIter<&V> {
+        let mut found = Vec::new();
+        self.visit(
+            0,
+            self.items.len(),
+            &|max_right| max_right.contains(t),
+            &|left| left.contains(t),
+            &|i| i.contains(t),
+            &mut found,
+        );
+        found.into_iter()
+    }
+
+    /// Values of the intervals which overlap with `q`, in ascending order of the left boundaries.
+    pub fn query_overlap(&self, q: &Interval<T, L, R>) -> alloc::vec::IntoIter<&V> {
+        let mut found = Vec::new();
+        self.visit(
+            0,
+            self.items.len(),
+            &|max_right| is_valid_interval(q.left(), max_right),
+            &|left| is_valid_interval(left, q.right()),
+            &|i| i.overlaps(q),
+            &mut found,
+        );
+        found.into_iter()
+    }
+
+    /// `reaches(max_right)` must be false if no interval in the subtree can hit,
+    /// and `starts(left)` must be false if no interval starting at or after `left` can hit.
+    fn visit<'a>(
+        &'a self,
+        lo: usize,
+        hi: usize,
+        reaches: &impl Fn(&RightBounded<T, R>) -> bool,
+        starts: &impl Fn(&LeftBounded<T, L>) -> bool,
+        hits: &impl Fn(&Interval<T, L, R>) -> bool,
+        found: &mut Vec<&'a V>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        if !reaches(&self.max_right[mid]) {
+            return;
+        }
+        self.visit(lo, mid, reaches, starts, hits, found);
+        let (interval, value) = &self.items[mid];
+        if starts(interval.left()) {
+            if hits(interval) {
+                found.push(value);
+            }
+            self.visit(mid + 1, hi, reaches, starts, hits, found);
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone, V, L: BoundaryOf<Left>, R: BoundaryOf<Right>>
+    FromIterator<(Interval<T, L, R>, V)> for IntervalTree<T, V, L, R>
+{
+    fn from_iter<I: IntoIterator<Item = (Interval<T, L, R>, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
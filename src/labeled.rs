@@ -0,0 +1,68 @@
+use crate::{Inclusive, Interval};
+
+/// Interval carrying an associated value. Methods of `Interval` are available through `Deref`.
+/// ```
+/// use inter_val::{Labeled, Inclusive, Exclusive};
+/// let brackets = [
+///     Inclusive.at(0).to(Exclusive.at(10_000)).with_value(0.1),
+///     Inclusive.at(10_000).to(Exclusive.at(40_000)).with_value(0.2),
+/// ];
+/// let rate = brackets.iter().find(|b| b.contains(&25_000)).map(|b| b.value);
+/// assert_eq!(rate, Some(0.2));
+///
+/// let q = Inclusive.at(5_000).to(Exclusive.at(15_000));
+/// assert!(brackets.iter().all(|b| b.overlaps(&q)));
+/// assert!(brackets[1].includes(&Inclusive.at(20_000).to(Exclusive.at(30_000))));
+///
+/// let Labeled { interval, value } = brackets[0];
+/// assert_eq!((interval.inf(), value), (&0, 0.1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Labeled<T, V, L = Inclusive, R = L> {
+    pub interval: Interval<T, L, R>,
+    pub value: V,
+}
+
+impl<T, V, L, R> core::ops::Deref for Labeled<T, V, L, R> {
+    type Target = Interval<T, L, R>;
+    fn deref(&self) -> &Self::Target {
+        &self.interval
+    }
+}
+
+impl<T, V, L, R> Labeled<T, V, L, R> {
+    pub fn new(interval: Interval<T, L, R>, value: V) -> Self {
+        Self { interval, value }
+    }
+
+    /// Replace the value while keeping the interval.
+    pub fn map_value<W>(self, f: impl FnOnce(V) -> W) -> Labeled<T, W, L, R> {
+        Labeled {
+            interval: self.interval,
+            value: f(self.value),
+        }
+    }
+
+    pub fn into_tuple(self) -> (Interval<T, L, R>, V) {
+        (self.interval, self.value)
+    }
+}
+
+impl<T, V, L, R> From<(Interval<T, L, R>, V)> for Labeled<T, V, L, R> {
+    fn from((interval, value): (Interval<T, L, R>, V)) -> Self {
+        Self { interval, value }
+    }
+}
+
+impl<T, L, R> Interval<T, L, R> {
+    /// Pair `self` with an associated value.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3)).with_value("low");
+    /// assert!(a.contains(&2));
+    /// assert_eq!(a.value, "low");
+    /// ```
+    pub fn with_value<V>(self, value: V) -> Labeled<T, V, L, R> {
+        Labeled::new(self, value)
+    }
+}
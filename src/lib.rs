@@ -56,28 +56,102 @@
 //! let b = a.hull(&[12.3, 7.5]);
 //! assert_eq!(b, Box2::between(&[0.0, 5.0], &[12.3, 20.0]));
 //! ```
+//!
+//! # Custom scalar types
+//! Any `PartialOrd` type can be a boundary; there is no scalar trait to implement.
+//! To validate raw values on ingestion, e.g. like rejecting NaN, wrap them in a newtype with a fallible conversion
+//! and convert by `Interval::try_convert()`, which also rejects an empty result.
+//! ```
+//! use inter_val::{Interval, Inclusive, Exclusive, TryMapError};
+//!
+//! /// Fixed-point number with 4 decimal places.
+//! #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+//! struct Fixed(i64);
+//!
+//! impl TryFrom<f64> for Fixed {
+//!     type Error = &'static str;
+//!     fn try_from(x: f64) -> Result<Self, Self::Error> {
+//!         let scaled = (x * 1.0e4).round();
+//!         (scaled.abs() < i64::MAX as f64).then_some(Fixed(scaled as i64)).ok_or("out of range")
+//!     }
+//! }
+//!
+//! let a = Inclusive.at(0.25).to(Exclusive.at(1.5)).try_convert::<Fixed>().unwrap();
+//! assert!(a.contains(&Fixed(2_500)) && !a.contains(&Fixed(15_000)));
+//! assert!(matches!(Inclusive.between(0.0, f64::INFINITY).try_convert::<Fixed>(), Err(TryMapError::Map(_))));
+//! ```
+//!
+//! # `no_std`
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The `alloc` feature enables the collection types such as `IntervalSet`, `IntervalMap`, `MembershipIndex`, `IntervalTree`, `DenseIntervalSet`, `Partition`, and `Histogram`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod arithmetic;
 mod bound;
 mod bound_type;
+mod circular;
 mod converters;
+#[cfg(feature = "alloc")]
+mod dense_interval_set;
 mod half;
+#[cfg(feature = "alloc")]
+mod histogram;
+#[cfg(feature = "approx")]
+mod impl_approx;
+#[cfg(feature = "chrono")]
+mod impl_chrono;
+#[cfg(feature = "proptest")]
+mod impl_proptest;
+#[cfg(feature = "rand")]
+mod impl_rand;
 mod interval;
 mod interval_box;
+#[cfg(feature = "alloc")]
+mod interval_map;
+#[cfg(feature = "alloc")]
+mod interval_set;
+#[cfg(feature = "alloc")]
+mod interval_tree;
+mod labeled;
+#[cfg(feature = "alloc")]
+mod membership_index;
 mod ndim;
 mod nullable;
+#[cfg(feature = "alloc")]
+mod partition;
+mod pow;
+#[cfg(feature = "serde")]
+pub mod serde_bracket;
 mod std_range;
 mod tests;
 mod traits;
 
-use bound_type::{Left, Right};
-use traits::BoundaryOf;
-
 pub use bound::Bound;
-pub use bound_type::{BoundType, Exclusive, Inclusive};
+pub use bound_type::{BoundType, Exclusive, Inclusive, Left, Right, SideInclusion, Unbounded};
+pub use circular::CircularInterval;
+#[cfg(feature = "alloc")]
+pub use dense_interval_set::DenseIntervalSet;
 pub use half::{HalfBounded, LeftBounded, RightBounded};
-pub use interval::Interval;
+#[cfg(feature = "alloc")]
+pub use histogram::Histogram;
+pub use interval::{Interval, Lexicographic, Which};
 pub use interval_box::BoxN;
-pub use ndim::NDim;
+#[cfg(feature = "alloc")]
+pub use interval_map::IntervalMap;
+#[cfg(feature = "alloc")]
+pub use interval_set::IntervalSet;
+#[cfg(feature = "alloc")]
+pub use interval_tree::IntervalTree;
+pub use labeled::Labeled;
+#[cfg(feature = "alloc")]
+pub use membership_index::MembershipIndex;
+pub use ndim::{NDim, Xy, Xyz, Xyzw};
 pub use nullable::Nullable;
+#[cfg(feature = "alloc")]
+pub use partition::Partition;
+pub use traits::{Boundary, BoundaryOf, Ceil, Flip, Floor, Infinity};
 
 impl Inclusive {
     pub fn at<T>(self, t: T) -> Bound<T, Self> {
@@ -114,6 +188,38 @@ impl Exclusive {
         Interval::hull_many(items)
     }
 }
+impl Unbounded {
+    /// Left boundary at *-∞*.
+    /// ```
+    /// use inter_val::{Unbounded, Inclusive};
+    /// let a = Unbounded::neg_infinity().to(Inclusive.at(3.0)); // (-∞, 3]
+    /// assert!(a.contains(&-1.0e300));
+    /// assert!(a.contains(&3.0));
+    /// assert!(!a.contains(&3.1));
+    /// assert_eq!(a.inf(), &f64::NEG_INFINITY);
+    /// assert_eq!(a.measure(), f64::INFINITY);
+    /// ```
+    pub fn neg_infinity<T: Infinity>() -> Bound<T, Self> {
+        Bound {
+            limit: T::neg_infinity(),
+            bound_type: Self(()),
+        }
+    }
+    /// Right boundary at *+∞*.
+    /// ```
+    /// use inter_val::{Unbounded, Exclusive};
+    /// let a = Exclusive.at(0.0).to(Unbounded::infinity()); // (0, +∞)
+    /// assert!(!a.contains(&0.0));
+    /// assert!(a.contains(&f64::MAX));
+    /// assert_eq!(a.sup(), &f64::INFINITY);
+    /// ```
+    pub fn infinity<T: Infinity>() -> Bound<T, Self> {
+        Bound {
+            limit: T::infinity(),
+            bound_type: Self(()),
+        }
+    }
+}
 impl BoundType {
     pub fn at<T>(self, t: T) -> Bound<T, Self> {
         Bound {
@@ -143,6 +249,25 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> Bound<T, B> {
 #[error("left boundary must be less than or equal to right boundary")]
 pub struct IntervalIsEmpty;
 
+/// Error of `Interval::from_samples()`.
+#[derive(Debug, thiserror::Error)]
+#[error("NaN is not allowed as a boundary")]
+pub struct FloatIsNan;
+
+/// Error of converting `BoundType` into `Inclusive` or `Exclusive`.
+#[derive(Debug, thiserror::Error)]
+#[error("unexpected boundary type: {0:?}")]
+pub struct BoundTypeMismatch(pub BoundType);
+
+/// Error of `Interval::try_map()`.
+#[derive(Debug, thiserror::Error)]
+pub enum TryMapError<E> {
+    #[error("failed to map boundary: {0}")]
+    Map(E),
+    #[error(transparent)]
+    IntervalIsEmpty(#[from] IntervalIsEmpty),
+}
+
 pub type OpenInterval<T> = Interval<T, Exclusive>;
 pub type GeneralInterval<T> = Interval<T, BoundType>;
 pub type Box2<T, L = Inclusive, R = L> = BoxN<2, T, L, R>;
@@ -0,0 +1,74 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::IntervalSet;
+use alloc::vec::Vec;
+
+/// Read-only index for fast point-in-set queries, built from an [`IntervalSet`].
+/// All boundaries are flattened into one sorted array, so `contains()` is a single binary search
+/// followed by a parity check, or an inclusivity check on an exact boundary hit.
+/// ```
+/// use inter_val::{IntervalSet, MembershipIndex, Inclusive, Exclusive};
+/// let set: IntervalSet<_, _, _> = [
+///     Inclusive.at(0.0).to(Exclusive.at(1.0)),
+///     Inclusive.at(2.0).to(Exclusive.at(3.5)),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let index = MembershipIndex::from(&set);
+/// assert!(index.contains(&0.0) && index.contains(&0.5) && !index.contains(&1.0));
+/// assert!(!index.contains(&1.5) && index.contains(&2.0) && !index.contains(&3.5));
+/// assert!(!index.contains(&-1.0) && !index.contains(&4.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipIndex<T> {
+    boundaries: Vec<T>,
+    inclusive: Vec<bool>,
+}
+
+impl<T: PartialOrd> MembershipIndex<T> {
+    /// ```
+    /// use inter_val::{BoundType, IntervalSet, MembershipIndex, Inclusive, Exclusive};
+    /// let index = MembershipIndex::from(&IntervalSet::<f64, BoundType>::default());
+    /// assert!(!index.contains(&0.0));
+    ///
+    /// let set: IntervalSet<_, BoundType> = [
+    ///     Inclusive.at(0.0).to(Exclusive.at(1.0)).into(),
+    ///     Exclusive.at(1.0).to(Inclusive.at(2.0)).into(),
+    ///     Inclusive.between(3.0, 3.0).into(),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let index = MembershipIndex::from(&set);
+    /// assert!(index.contains(&0.0) && !index.contains(&1.0) && index.contains(&2.0));
+    /// assert!(!index.contains(&2.5) && index.contains(&3.0) && !index.contains(&3.5));
+    /// ```
+    pub fn contains(&self, t: &T) -> bool {
+        let i = self.boundaries.partition_point(|b| b < t);
+        match self.boundaries.get(i) {
+            Some(b) if b == t => self.inclusive[i],
+            _ => i % 2 == 1,
+        }
+    }
+}
+
+impl<T, L, R> From<&IntervalSet<T, L, R>> for MembershipIndex<T>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn from(set: &IntervalSet<T, L, R>) -> Self {
+        let mut boundaries = Vec::with_capacity(set.len() * 2);
+        let mut inclusive = Vec::with_capacity(set.len() * 2);
+        for item in set {
+            boundaries.push(item.inf().clone());
+            inclusive.push(item.is_left_closed());
+            boundaries.push(item.sup().clone());
+            inclusive.push(item.is_right_closed());
+        }
+        Self {
+            boundaries,
+            inclusive,
+        }
+    }
+}
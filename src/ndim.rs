@@ -1,3 +1,4 @@
+#[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NDim<const N: usize, T>(pub [T; N]);
 
@@ -31,37 +32,37 @@ pub struct Xyzw<T> {
     pub w: T,
 }
 
-impl<T> std::ops::Deref for NDim<2, T> {
+impl<T> core::ops::Deref for NDim<2, T> {
     type Target = Xy<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xy()
     }
 }
-impl<T> std::ops::DerefMut for NDim<2, T> {
+impl<T> core::ops::DerefMut for NDim<2, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xy_mut()
     }
 }
-impl<T> std::ops::Deref for NDim<3, T> {
+impl<T> core::ops::Deref for NDim<3, T> {
     type Target = Xyz<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xyz()
     }
 }
-impl<T> std::ops::DerefMut for NDim<3, T> {
+impl<T> core::ops::DerefMut for NDim<3, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xyz_mut()
     }
 }
-impl<T> std::ops::Deref for NDim<4, T> {
+impl<T> core::ops::Deref for NDim<4, T> {
     type Target = Xyzw<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xyzw()
     }
 }
-impl<T> std::ops::DerefMut for NDim<4, T> {
+impl<T> core::ops::DerefMut for NDim<4, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        self.as_xyzw_mut()
     }
 }
 
@@ -75,7 +76,7 @@ impl<const N: usize, T> NDim<N, T> {
     pub fn into_array(self) -> [T; N] {
         self.0
     }
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.0.iter()
     }
 }
@@ -83,24 +84,72 @@ impl<T> NDim<2, T> {
     pub fn new(x: T, y: T) -> Self {
         Self([x, y])
     }
+
+    /// View as named fields, which is also available through `Deref`.
+    /// ```
+    /// use inter_val::{NDim, Xy};
+    /// let p = NDim::<2, i32>::new(1, 2);
+    /// assert_eq!(p.as_xy(), &Xy { x: 1, y: 2 });
+    /// assert_eq!(p.y, 2);
+    /// ```
+    pub fn as_xy(&self) -> &Xy<T> {
+        // SAFETY: `NDim` is `repr(transparent)` over `[T; 2]`, whose layout is the same as `repr(C)` `Xy<T>`.
+        unsafe { &*(self as *const Self as *const Xy<T>) }
+    }
+    pub fn as_xy_mut(&mut self) -> &mut Xy<T> {
+        // SAFETY: Same as `as_xy()`.
+        unsafe { &mut *(self as *mut Self as *mut Xy<T>) }
+    }
 }
 impl<T> NDim<3, T> {
     pub fn new(x: T, y: T, z: T) -> Self {
         Self([x, y, z])
     }
+
+    /// View as named fields, which is also available through `Deref`.
+    /// ```
+    /// use inter_val::{NDim, Xyz};
+    /// let p = NDim::<3, i32>::new(1, 2, 3);
+    /// assert_eq!(p.as_xyz(), &Xyz { x: 1, y: 2, z: 3 });
+    /// assert_eq!(p.z, 3);
+    /// ```
+    pub fn as_xyz(&self) -> &Xyz<T> {
+        // SAFETY: `NDim` is `repr(transparent)` over `[T; 3]`, whose layout is the same as `repr(C)` `Xyz<T>`.
+        unsafe { &*(self as *const Self as *const Xyz<T>) }
+    }
+    pub fn as_xyz_mut(&mut self) -> &mut Xyz<T> {
+        // SAFETY: Same as `as_xyz()`.
+        unsafe { &mut *(self as *mut Self as *mut Xyz<T>) }
+    }
 }
 impl<T> NDim<4, T> {
     pub fn new(x: T, y: T, z: T, w: T) -> Self {
         Self([x, y, z, w])
     }
-}
-impl<const N: usize, T> std::ops::Index<usize> for NDim<N, T> {
+
+    /// View as named fields, which is also available through `Deref`.
+    /// ```
+    /// use inter_val::{NDim, Xyzw};
+    /// let p = NDim::<4, i32>::new(1, 2, 3, 4);
+    /// assert_eq!(p.as_xyzw(), &Xyzw { x: 1, y: 2, z: 3, w: 4 });
+    /// assert_eq!(p.w, 4);
+    /// ```
+    pub fn as_xyzw(&self) -> &Xyzw<T> {
+        // SAFETY: `NDim` is `repr(transparent)` over `[T; 4]`, whose layout is the same as `repr(C)` `Xyzw<T>`.
+        unsafe { &*(self as *const Self as *const Xyzw<T>) }
+    }
+    pub fn as_xyzw_mut(&mut self) -> &mut Xyzw<T> {
+        // SAFETY: Same as `as_xyzw()`.
+        unsafe { &mut *(self as *mut Self as *mut Xyzw<T>) }
+    }
+}
+impl<const N: usize, T> core::ops::Index<usize> for NDim<N, T> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
-impl<const N: usize, T> std::ops::IndexMut<usize> for NDim<N, T> {
+impl<const N: usize, T> core::ops::IndexMut<usize> for NDim<N, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
@@ -117,14 +166,14 @@ impl<const N: usize, T> From<NDim<N, T>> for [T; N] {
 }
 impl<const N: usize, T> IntoIterator for NDim<N, T> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, N>;
+    type IntoIter = core::array::IntoIter<T, N>;
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
 impl<'a, const N: usize, T> IntoIterator for &'a NDim<N, T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
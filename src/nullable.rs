@@ -20,13 +20,13 @@ use crate::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Nullable<T>(pub Option<T>);
 
-impl<T> std::ops::Deref for Nullable<T> {
+impl<T> core::ops::Deref for Nullable<T> {
     type Target = Option<T>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<T> std::ops::DerefMut for Nullable<T> {
+impl<T> core::ops::DerefMut for Nullable<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -65,6 +65,25 @@ impl<T> Nullable<T> {
     }
 }
 
+/// `Nullable<Interval>` as a possibly empty interval.
+impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Nullable<Interval<T, L, R>> {
+    /// Always `false` for the empty interval.
+    pub fn contains(&self, t: &T) -> bool {
+        self.0.as_ref().is_some_and(|i| i.contains(t))
+    }
+
+    /// Intersection, which is empty if either operand is empty.
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.intersection(b).into(),
+            _ => Self::NULL,
+        }
+    }
+}
+
 /// ```
 /// use inter_val::{Nullable, Interval, Inclusive, Exclusive};
 /// let a = Inclusive.at(0).to(Exclusive.at(3));  // [0, 3)
@@ -74,7 +93,7 @@ impl<T> Nullable<T> {
 /// assert_eq!(span.as_ref().unwrap().left().limit, 0);
 /// assert_eq!(span.as_ref().unwrap().right().limit, 10);
 /// ```
-impl<T, L, R> std::iter::Sum<Interval<T, L, R>> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::Sum<Interval<T, L, R>> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone,
     L: BoundaryOf<Left>,
@@ -85,12 +104,29 @@ where
     }
 }
 
+/// ```
+/// use inter_val::{Nullable, Interval, Inclusive, Exclusive};
+/// let items = [Inclusive.at(0).to(Exclusive.at(3)), Inclusive.at(8).to(Exclusive.at(10))];
+/// let span: Nullable<Interval<_, _, _>> = items.iter().sum(); // [0, 10)
+/// assert_eq!(span.unwrap(), Inclusive.at(0).to(Exclusive.at(10)));
+/// ```
+impl<'a, T, L, R> core::iter::Sum<&'a Interval<T, L, R>> for Nullable<Interval<T, L, R>>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn sum<I: Iterator<Item = &'a Interval<T, L, R>>>(iter: I) -> Self {
+        Interval::span_many(iter).into()
+    }
+}
+
 /// ```
 /// use inter_val::{Interval, Nullable};
 /// let a: Nullable<Interval<i32>> = vec![1, 6, 2, 8, 3].into_iter().sum();
 /// assert_eq!(a.unwrap(), Interval::between(1, 8));
 /// ```
-impl<T, L, R> std::iter::Sum<T> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::Sum<T> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone + Into<Bound<T, L>> + Into<Bound<T, R>>,
     L: BoundaryOf<Left>,
@@ -106,7 +142,7 @@ where
 /// let a = vec![1, 6, 2, 8, 3].into_iter().collect::<Nullable<Interval<i32>>>();
 /// assert_eq!(a.unwrap(), Interval::between(1, 8));
 /// ```
-impl<T, L, R> std::iter::FromIterator<T> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::FromIterator<T> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone + Into<Bound<T, L>> + Into<Bound<T, R>>,
     L: BoundaryOf<Left>,
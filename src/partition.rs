@@ -0,0 +1,55 @@
+use crate::{Exclusive, Inclusive, Interval};
+use alloc::vec::Vec;
+
+/// Lookup table of contiguous half-open buckets *[b₀, b₁), [b₁, b₂), ...* with associated values.
+/// ```
+/// use inter_val::{Partition, Inclusive, Exclusive};
+/// let tiers = Partition::new([0.0, 100.0, 300.0, f64::INFINITY], ["fast", "ok", "slow"]).unwrap();
+/// assert_eq!(tiers.bucket_of(&42.0).map(|(_, v)| *v), Some("fast"));
+/// assert_eq!(tiers.bucket_of(&100.0).map(|(_, v)| *v), Some("ok"));
+/// assert_eq!(tiers.bucket_of(&1e9).map(|(_, v)| *v), Some("slow"));
+/// assert!(tiers.bucket_of(&-1.0).is_none());
+///
+/// let (bucket, _) = tiers.bucket_of(&150.0).unwrap();
+/// assert_eq!(bucket, &Inclusive.at(100.0).to(Exclusive.at(300.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partition<T, V> {
+    buckets: Vec<(Interval<T, Inclusive, Exclusive>, V)>,
+}
+
+impl<T: PartialOrd + Clone, V> Partition<T, V> {
+    /// Returns `None` if `breakpoints` is not strictly increasing,
+    /// or the number of `values` is not the number of `breakpoints` minus one.
+    pub fn new(
+        breakpoints: impl IntoIterator<Item = T>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Option<Self> {
+        let breakpoints: Vec<T> = breakpoints.into_iter().collect();
+        let mut values = values.into_iter();
+        let buckets = breakpoints
+            .windows(2)
+            .map(|w| {
+                let bucket =
+                    Interval::try_new(Inclusive.at(w[0].clone()), Exclusive.at(w[1].clone()))?;
+                Some((bucket, values.next()?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        values.next().is_none().then_some(Self { buckets })
+    }
+
+    /// The bucket containing `t` and its value, found by binary search.
+    pub fn bucket_of(&self, t: &T) -> Option<(&Interval<T, Inclusive, Exclusive>, &V)> {
+        let i = self
+            .buckets
+            .partition_point(|(bucket, _)| bucket.sup() <= t);
+        self.buckets
+            .get(i)
+            .filter(|(bucket, _)| bucket.contains(t))
+            .map(|(bucket, value)| (bucket, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T, Inclusive, Exclusive>, &V)> {
+        self.buckets.iter().map(|(bucket, value)| (bucket, value))
+    }
+}
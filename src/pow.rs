@@ -0,0 +1,57 @@
+use crate::bound_type::{Left, Right};
+use crate::half::partial_max;
+use crate::traits::BoundaryOf;
+use crate::{BoundType, Inclusive, Interval, RightBounded};
+
+impl<T, L, R> Interval<T, L, R>
+where
+    T: num::Float,
+    L: BoundaryOf<Left> + Into<BoundType>,
+    R: BoundaryOf<Right> + Into<BoundType>,
+{
+    /// Image of `self` under *x ↦ xⁿ*.
+    /// Returns `None` if `n < 0` and the closure of `self` contains zero, where the image is unbounded.
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(-2.0).to(Inclusive.at(1.0));  // (-2, 1]
+    ///
+    /// // Odd powers are monotonic.
+    /// let b = a.powi(3).unwrap();
+    /// assert_eq!((b.inf(), b.sup()), (&-8.0, &1.0));
+    /// assert_eq!(b.kind(), (BoundType::Exclusive, BoundType::Inclusive));
+    ///
+    /// // Even powers of an interval straddling zero have the minimum 0.
+    /// let b = a.powi(2).unwrap();
+    /// assert_eq!((b.inf(), b.sup()), (&0.0, &4.0));
+    /// assert_eq!(b.kind(), (BoundType::Inclusive, BoundType::Exclusive));
+    ///
+    /// // Negative powers swap the boundaries where decreasing.
+    /// let b = Inclusive.at(2.0).to(Exclusive.at(4.0)).powi(-1).unwrap();
+    /// assert_eq!((b.inf(), b.sup()), (&0.25, &0.5));
+    /// assert_eq!(b.kind(), (BoundType::Exclusive, BoundType::Inclusive));
+    /// assert!(a.powi(-1).is_none());
+    /// ```
+    pub fn powi(&self, n: i32) -> Option<Interval<T, BoundType>> {
+        if n == 0 {
+            return Some(Inclusive.at(T::one()).to(Inclusive.at(T::one())).into());
+        }
+        let (inf, sup) = (*self.inf(), *self.sup());
+        if n < 0 && inf <= T::zero() && T::zero() <= sup {
+            return None;
+        }
+        let lower = self.left().bound_type().at(inf.powi(n));
+        let upper = self.right().bound_type().at(sup.powi(n));
+        if n % 2 == 0 && inf < T::zero() && T::zero() < sup {
+            let lower: RightBounded<_, _> = lower.into();
+            let upper: RightBounded<_, _> = upper.into();
+            Interval::try_new(
+                BoundType::Inclusive.at(T::zero()),
+                partial_max(lower, upper).0,
+            )
+        } else if lower.limit <= upper.limit {
+            Interval::try_new(lower, upper)
+        } else {
+            Interval::try_new(upper, lower)
+        }
+    }
+}
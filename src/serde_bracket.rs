@@ -0,0 +1,92 @@
+//! (De)serialize an `Interval` as a compact string like `"[0,3)"`, to be attached per field by `#[serde(with = "...")]`.
+//! ```
+//! use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "inter_val::serde_bracket")]
+//!     range: Interval<i32, Inclusive, Exclusive>,
+//!     #[serde(with = "inter_val::serde_bracket")]
+//!     tolerance: Interval<f64, BoundType>,
+//! }
+//!
+//! let config = Config {
+//!     range: Inclusive.at(0).to(Exclusive.at(3)),
+//!     tolerance: Exclusive.at(-0.5).to(Inclusive.at(0.5)).into(),
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"range":"[0,3)","tolerance":"(-0.5,0.5]"}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! assert!(serde_json::from_str::<Config>(r#"{"range":"(0,3)","tolerance":"[0,1]"}"#).is_err());
+//! assert!(serde_json::from_str::<Config>(r#"{"range":"[3,0)","tolerance":"[0,1]"}"#).is_err());
+//! ```
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Bound, BoundType, Interval};
+use alloc::{format, string::String};
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S, T, L, R>(interval: &Interval<T, L, R>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: core::fmt::Display,
+    L: Copy + Into<BoundType>,
+    R: Copy + Into<BoundType>,
+{
+    let (l, r) = interval.kind();
+    let l = match l {
+        BoundType::Inclusive => '[',
+        BoundType::Exclusive => '(',
+    };
+    let r = match r {
+        BoundType::Inclusive => ']',
+        BoundType::Exclusive => ')',
+    };
+    serializer.collect_str(&format_args!(
+        "{}{},{}{}",
+        l,
+        interval.left().limit,
+        interval.right().limit,
+        r
+    ))
+}
+
+pub fn deserialize<'de, D, T, L, R>(deserializer: D) -> Result<Interval<T, L, R>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: core::str::FromStr + PartialOrd,
+    T::Err: core::fmt::Display,
+    L: BoundaryOf<Left> + TryFrom<BoundType>,
+    R: BoundaryOf<Right> + TryFrom<BoundType>,
+    L::Error: core::fmt::Display,
+    R::Error: core::fmt::Display,
+{
+    let s = String::deserialize(deserializer)?;
+    let s = s.trim();
+    let invalid = || D::Error::custom(format!("invalid interval: {s:?}"));
+    let left_type = match s.chars().next() {
+        Some('[') => BoundType::Inclusive,
+        Some('(') => BoundType::Exclusive,
+        _ => return Err(invalid()),
+    };
+    let right_type = match s.chars().next_back() {
+        Some(']') => BoundType::Inclusive,
+        Some(')') => BoundType::Exclusive,
+        _ => return Err(invalid()),
+    };
+    let (a, b) = s
+        .get(1..s.len() - 1)
+        .and_then(|inner| inner.split_once(','))
+        .ok_or_else(invalid)?;
+    let left = Bound {
+        limit: a.trim().parse().map_err(D::Error::custom)?,
+        bound_type: L::try_from(left_type).map_err(D::Error::custom)?,
+    };
+    let right = Bound {
+        limit: b.trim().parse().map_err(D::Error::custom)?,
+        bound_type: R::try_from(right_type).map_err(D::Error::custom)?,
+    };
+    Interval::try_new(left, right).ok_or_else(|| D::Error::custom(crate::IntervalIsEmpty))
+}
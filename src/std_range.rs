@@ -1,6 +1,26 @@
 mod impl_range_bounds {
-    use crate::{Exclusive, Inclusive, LeftBounded, RightBounded};
-    use std::ops::{Bound, RangeBounds};
+    use crate::traits::Boundary;
+    use crate::{Exclusive, Inclusive, Interval, LeftBounded, RightBounded};
+    use core::ops::{Bound, RangeBounds};
+
+    /// ```
+    /// use inter_val::{BoundType, Interval, Inclusive, Exclusive};
+    /// use std::collections::BTreeSet;
+    /// let set: BTreeSet<_> = (0..10).collect();
+    /// let a = Exclusive.at(2).to(Inclusive.at(5));
+    /// assert_eq!(set.range(a).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    ///
+    /// let b: Interval<i32, BoundType> = Inclusive.at(7).to(Exclusive.at(9)).into();
+    /// assert_eq!(set.range(b).copied().collect::<Vec<_>>(), vec![7, 8]);
+    /// ```
+    impl<T, L: Boundary, R: Boundary> RangeBounds<T> for Interval<T, L, R> {
+        fn start_bound(&self) -> Bound<&T> {
+            self.bounds().0
+        }
+        fn end_bound(&self) -> Bound<&T> {
+            self.bounds().1
+        }
+    }
 
     impl<T: PartialOrd> RangeBounds<T> for LeftBounded<T, Inclusive> {
         fn start_bound(&self) -> Bound<&T> {
@@ -37,7 +57,7 @@ mod impl_range_bounds {
 }
 
 mod converters {
-    use crate::{Exclusive, Inclusive, Interval, IntervalIsEmpty};
+    use crate::{Exclusive, Inclusive, Interval, IntervalIsEmpty, LeftBounded, RightBounded};
 
     /// ```
     /// use std::any::{Any, TypeId};
@@ -47,9 +67,9 @@ mod converters {
     /// assert_eq!(a.left().limit, 2);
     /// assert_eq!(a.right().limit, 4);
     /// ```
-    impl<T: PartialOrd> TryFrom<std::ops::Range<T>> for Interval<T, Inclusive, Exclusive> {
+    impl<T: PartialOrd> TryFrom<core::ops::Range<T>> for Interval<T, Inclusive, Exclusive> {
         type Error = IntervalIsEmpty;
-        fn try_from(r: std::ops::Range<T>) -> Result<Self, Self::Error> {
+        fn try_from(r: core::ops::Range<T>) -> Result<Self, Self::Error> {
             Self::try_new(r.start.into(), r.end.into()).ok_or(IntervalIsEmpty)
         }
     }
@@ -62,9 +82,9 @@ mod converters {
     /// assert_eq!(a.left().limit, 2);
     /// assert_eq!(a.right().limit, 4);
     /// ```
-    impl<T: PartialOrd> TryFrom<std::ops::RangeInclusive<T>> for Interval<T, Inclusive> {
+    impl<T: PartialOrd> TryFrom<core::ops::RangeInclusive<T>> for Interval<T, Inclusive> {
         type Error = IntervalIsEmpty;
-        fn try_from(r: std::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
+        fn try_from(r: core::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
             let (left, right) = r.into_inner();
             Self::try_new(left.into(), right.into()).ok_or(IntervalIsEmpty)
         }
@@ -77,7 +97,7 @@ mod converters {
     /// assert_eq!(dst.start, 0);
     /// assert_eq!(dst.end, 10);
     /// ```
-    impl<T> From<Interval<T, Inclusive, Exclusive>> for std::ops::Range<T> {
+    impl<T> From<Interval<T, Inclusive, Exclusive>> for core::ops::Range<T> {
         fn from(i: Interval<T, Inclusive, Exclusive>) -> Self {
             i.left.0.limit..i.right.0.limit
         }
@@ -90,9 +110,81 @@ mod converters {
     /// assert_eq!(dst.start(), &0);
     /// assert_eq!(dst.end(), &10);
     /// ```
-    impl<T> From<Interval<T, Inclusive, Inclusive>> for std::ops::RangeInclusive<T> {
+    impl<T> From<Interval<T, Inclusive, Inclusive>> for core::ops::RangeInclusive<T> {
         fn from(i: Interval<T, Inclusive, Inclusive>) -> Self {
             i.left.0.limit..=i.right.0.limit
         }
     }
+
+    /// ```
+    /// use inter_val::{LeftBounded, Inclusive};
+    /// let a: LeftBounded<_, _> = (2..).into();
+    /// assert_eq!(a, Inclusive.at(2).into());
+    /// assert!(a.contains(&2));
+    /// ```
+    impl<T> From<core::ops::RangeFrom<T>> for LeftBounded<T, Inclusive> {
+        fn from(r: core::ops::RangeFrom<T>) -> Self {
+            Inclusive.at(r.start).into()
+        }
+    }
+
+    /// ```
+    /// use inter_val::{RightBounded, Exclusive};
+    /// let a: RightBounded<_, _> = (..4).into();
+    /// assert_eq!(a, Exclusive.at(4).into());
+    /// assert!(!a.contains(&4));
+    /// ```
+    impl<T> From<core::ops::RangeTo<T>> for RightBounded<T, Exclusive> {
+        fn from(r: core::ops::RangeTo<T>) -> Self {
+            Exclusive.at(r.end).into()
+        }
+    }
+
+    /// ```
+    /// use inter_val::{RightBounded, Inclusive};
+    /// let a: RightBounded<_, _> = (..=4).into();
+    /// assert_eq!(a, Inclusive.at(4).into());
+    /// assert!(a.contains(&4));
+    /// ```
+    impl<T> From<core::ops::RangeToInclusive<T>> for RightBounded<T, Inclusive> {
+        fn from(r: core::ops::RangeToInclusive<T>) -> Self {
+            Inclusive.at(r.end).into()
+        }
+    }
+
+    /// ```
+    /// use inter_val::{LeftBounded, Inclusive};
+    /// let src: LeftBounded<_, _> = Inclusive.at(2).into();
+    /// let dst: std::ops::RangeFrom<i32> = src.into();
+    /// assert_eq!(dst.start, 2);
+    /// ```
+    impl<T> From<LeftBounded<T, Inclusive>> for core::ops::RangeFrom<T> {
+        fn from(b: LeftBounded<T, Inclusive>) -> Self {
+            b.0.limit..
+        }
+    }
+
+    /// ```
+    /// use inter_val::{RightBounded, Exclusive};
+    /// let src: RightBounded<_, _> = Exclusive.at(4).into();
+    /// let dst: std::ops::RangeTo<i32> = src.into();
+    /// assert_eq!(dst.end, 4);
+    /// ```
+    impl<T> From<RightBounded<T, Exclusive>> for core::ops::RangeTo<T> {
+        fn from(b: RightBounded<T, Exclusive>) -> Self {
+            ..b.0.limit
+        }
+    }
+
+    /// ```
+    /// use inter_val::{RightBounded, Inclusive};
+    /// let src: RightBounded<_, _> = Inclusive.at(4).into();
+    /// let dst: std::ops::RangeToInclusive<i32> = src.into();
+    /// assert_eq!(dst.end, 4);
+    /// ```
+    impl<T> From<RightBounded<T, Inclusive>> for core::ops::RangeToInclusive<T> {
+        fn from(b: RightBounded<T, Inclusive>) -> Self {
+            ..=b.0.limit
+        }
+    }
 }
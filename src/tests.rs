@@ -93,3 +93,215 @@ fn ordering() {
     let b: RightBounded<_, _> = BoundType::Exclusive.at(0).into();
     assert!(a > b);
 }
+
+#[test]
+fn subdivide_tiles_interval() {
+    let a = Exclusive.at(0.1).to(Inclusive.at(0.7));
+    let pieces: Vec<_> = a.subdivide(7).unwrap().collect();
+    assert_eq!(pieces.len(), 7);
+    assert_eq!(pieces[0].left().bound_type, BoundType::Exclusive);
+    assert_eq!(pieces[0].inf(), a.inf());
+    assert_eq!(pieces[6].right().bound_type, BoundType::Inclusive);
+    assert_eq!(pieces[6].sup(), a.sup());
+    for w in pieces.windows(2) {
+        assert_eq!(w[0].sup(), w[1].inf());
+        assert_eq!(w[0].right().bound_type, BoundType::Exclusive);
+        assert_eq!(w[1].left().bound_type, BoundType::Inclusive);
+    }
+}
+
+#[test]
+fn interval_into_range() {
+    let items = [10, 11, 12, 13, 14, 15];
+    let a = Inclusive.at(1).to(Exclusive.at(4));
+    assert_eq!(&items[std::ops::Range::from(a)], &[11, 12, 13]);
+
+    let a = Inclusive.at(2).to(Inclusive.at(4));
+    let mut sum = 0;
+    for i in std::ops::RangeInclusive::from(a) {
+        sum += items[i];
+    }
+    assert_eq!(sum, 12 + 13 + 14);
+}
+
+#[test]
+fn unbounded_interval() {
+    let a = Unbounded::neg_infinity().to(Inclusive.at(3.0)); // (-∞, 3]
+    let b = Unbounded::neg_infinity().to(Inclusive.at(5.0)); // (-∞, 5]
+    assert_typeid::<Interval<f64, Unbounded, Inclusive>>(&a);
+    assert_eq!(a.intersection(&b), Some(a));
+    assert_eq!(a.span(&b), b);
+    assert!(a.overlaps(&b));
+    assert!(b.includes(&a));
+    assert!(a.contains(&f64::MIN));
+
+    let whole: Interval<f64, Unbounded> = Unbounded::neg_infinity().to(Unbounded::infinity());
+    assert!(whole.contains(&f64::MIN));
+    assert!(whole.contains(&f64::MAX));
+    assert_eq!(whole.measure(), f64::INFINITY);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn interval_tree_matches_brute_force() {
+    let items: Vec<_> = (0..50)
+        .map(|i| {
+            let a = (i * 53) % 101;
+            Inclusive.at(a).to(Exclusive.at(a + 1 + (i * 29) % 20))
+        })
+        .zip(0..)
+        .collect();
+    let tree: IntervalTree<_, _, _, _> = items.iter().cloned().collect();
+    for t in -5..130 {
+        let mut expected: Vec<_> = items
+            .iter()
+            .filter(|(i, _)| i.contains(&t))
+            .map(|(_, v)| *v)
+            .collect();
+        let mut actual: Vec<_> = tree.query_point(&t).copied().collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+
+        let q = Inclusive.at(t).to(Exclusive.at(t + 7));
+        let mut expected: Vec<_> = items
+            .iter()
+            .filter(|(i, _)| i.overlaps(&q))
+            .map(|(_, v)| *v)
+            .collect();
+        let mut actual: Vec<_> = tree.query_overlap(&q).copied().collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn adjacency_depends_on_bound_types() {
+    let i = |a: i32, l: BoundType, b: i32, r: BoundType| l.at(a).to(r.at(b));
+    use BoundType::{Exclusive as E, Inclusive as I};
+    assert!(i(0, I, 3, E).is_adjacent(&i(3, I, 5, E))); // [0, 3) [3, 5)
+    assert!(i(0, I, 3, I).is_adjacent(&i(3, E, 5, E))); // [0, 3] (3, 5)
+    assert!(!i(0, I, 3, E).is_adjacent(&i(3, E, 5, E))); // [0, 3) (3, 5): gap at 3
+    assert!(!i(0, I, 3, I).is_adjacent(&i(3, I, 5, I))); // [0, 3] [3, 5]: overlapping
+    assert!(!i(0, I, 3, E).is_adjacent(&i(4, I, 5, E))); // [0, 3) [4, 5)
+    assert!(i(3, I, 5, E).is_adjacent(&i(0, I, 3, E))); // symmetric
+
+    let a = Exclusive.at(0.0).to(Exclusive.at(1.0));
+    let b = Inclusive.at(1.0).to(Inclusive.at(2.0)).into();
+    assert!(Interval::<_, BoundType>::from(a).is_adjacent(&b));
+}
+
+#[test]
+fn ndim_layout_matches_named_fields() {
+    use crate::ndim::{Xy, Xyz, Xyzw};
+    use core::mem::{align_of, size_of};
+    fn check<T>() {
+        assert_eq!(size_of::<NDim<2, T>>(), size_of::<Xy<T>>());
+        assert_eq!(size_of::<NDim<3, T>>(), size_of::<Xyz<T>>());
+        assert_eq!(size_of::<NDim<4, T>>(), size_of::<Xyzw<T>>());
+        assert_eq!(align_of::<NDim<2, T>>(), align_of::<Xy<T>>());
+        assert_eq!(align_of::<NDim<3, T>>(), align_of::<Xyz<T>>());
+        assert_eq!(align_of::<NDim<4, T>>(), align_of::<Xyzw<T>>());
+    }
+    check::<u8>();
+    check::<i32>();
+    check::<f64>();
+    check::<u128>();
+    check::<(u8, u32)>();
+    check::<[u16; 3]>();
+
+    let mut p = NDim::<3, f64>::new(1.0, 2.0, 3.0);
+    p.as_xyz_mut().y = 5.0;
+    p.z += 1.0;
+    assert_eq!(p, [1.0, 5.0, 4.0]);
+}
+
+#[test]
+fn union_of_general_intervals_flips_bound_types_at_runtime() {
+    use crate::traits::{Flip, IntoGeneral};
+    let bound_types = [BoundType::Inclusive, BoundType::Exclusive];
+    for l in bound_types {
+        for r in bound_types {
+            let a = Interval::new(BoundType::Inclusive.at(0), l.at(3));
+            let b = Interval::new(r.at(5), BoundType::Inclusive.at(8));
+            let union = a.union(&b);
+            assert_eq!(union.span, Inclusive.between(0, 8).into_general());
+            let gap = union.gap.unwrap();
+            assert_eq!(gap.left().bound_type, l.flip());
+            assert_eq!(gap.right().bound_type, r.flip());
+            for t in -1..10 {
+                let in_union = union.into_iter().any(|x| x.contains(&t));
+                assert_eq!(in_union, a.contains(&t) || b.contains(&t));
+                assert_eq!(gap.contains(&t), !in_union && union.span.contains(&t));
+            }
+        }
+    }
+
+    // Touching general intervals have no gap unless both ends are exclusive.
+    let mut acc = Interval::new(BoundType::Inclusive.at(0), BoundType::Exclusive.at(1));
+    for (l, r) in [(1, 2), (2, 3)] {
+        let x = Interval::new(BoundType::Inclusive.at(l), BoundType::Exclusive.at(r));
+        let union = acc.union(&x);
+        assert!(union.gap.is_none());
+        acc = union.span;
+    }
+    assert_eq!(acc.closure(), Inclusive.between(0, 3));
+    let x = Interval::new(BoundType::Exclusive.at(3), BoundType::Inclusive.at(4));
+    assert_eq!(
+        acc.union(&x).gap,
+        Some(Interval::new(
+            BoundType::Inclusive.at(3),
+            BoundType::Inclusive.at(3)
+        ))
+    );
+}
+
+#[test]
+fn nan_boundaries_are_rejected() {
+    let nan = f64::NAN;
+    for (a, b) in [(nan, 1.0), (0.0, nan), (nan, nan)] {
+        assert!(Interval::<f64, Inclusive>::try_between(a, b).is_none());
+        assert!(Interval::<f64, Exclusive>::try_between(a, b).is_none());
+        assert!(Interval::<f64, Inclusive, Exclusive>::try_between(a, b).is_none());
+        assert!(Interval::<f64, Exclusive, Inclusive>::try_between(a, b).is_none());
+        assert!(
+            Interval::try_new(BoundType::Inclusive.at(a), BoundType::Inclusive.at(b)).is_none()
+        );
+    }
+}
+
+#[test]
+fn unbounded_side_is_never_empty() {
+    let a = Interval::try_new(Unbounded::neg_infinity(), Exclusive.at(f64::MIN)).unwrap();
+    assert!(a.contains(&f64::NEG_INFINITY) && !a.contains(&f64::MIN));
+    assert_eq!(a.measure(), f64::INFINITY);
+    assert!(Interval::try_new(Unbounded::neg_infinity(), Inclusive.at(f64::NAN)).is_none());
+    assert!(Interval::try_new(Exclusive.at(f64::NAN), Unbounded::infinity()).is_none());
+
+    let b = Inclusive.at(0.0).to(Unbounded::infinity());
+    assert!(b.contains(&f64::MAX) && !b.contains(&-1.0));
+    assert!(b.lower_bound().contains(&-1.0) && !b.lower_bound().contains(&f64::MAX));
+}
+
+#[test]
+fn add_outward_encloses_exact_sum() {
+    let values: Vec<f32> = (0..60)
+        .map(|i| ((i * 7919) % 2003) as f32 / 97.0 - 10.0)
+        .collect();
+    for w in values.windows(4) {
+        let a = Interval::<f32>::between(w[0], w[1]);
+        let b = Interval::<f32>::between(w[2], w[3]);
+        // Sums of these f32 values are exact in f64.
+        let exact_inf = *a.inf() as f64 + *b.inf() as f64;
+        let exact_sup = *a.sup() as f64 + *b.sup() as f64;
+        let c = a.add_outward(b);
+        assert!(*c.inf() as f64 <= exact_inf && exact_inf < c.inf().next_up() as f64);
+        assert!(*c.sup() as f64 >= exact_sup && exact_sup > c.sup().next_down() as f64);
+        let exact_inf = *a.inf() as f64 - *b.sup() as f64;
+        let exact_sup = *a.sup() as f64 - *b.inf() as f64;
+        let c = a.sub_outward(b);
+        assert!(*c.inf() as f64 <= exact_inf && exact_inf < c.inf().next_up() as f64);
+        assert!(*c.sup() as f64 >= exact_sup && exact_sup > c.sup().next_down() as f64);
+    }
+}
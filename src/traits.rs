@@ -1,12 +1,15 @@
+/// Swap inclusive and exclusive, e.g., the boundary type on the other side of a boundary.
 pub trait Flip {
     type Flip: Flip<Flip = Self>;
     fn flip(self) -> Self::Flip;
 }
 
+/// Smallest integer contained in a left boundary.
 pub trait Ceil<T> {
     fn ceil(&self) -> T;
 }
 
+/// Largest integer contained in a right boundary.
 pub trait Floor<T> {
     fn floor(&self) -> T;
 }
@@ -16,11 +19,38 @@ pub(crate) trait IntoGeneral {
     fn into_general(self) -> Self::General;
 }
 
-pub trait Boundary: Flip + Eq + Copy {
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for crate::Inclusive {}
+    impl Sealed for crate::Exclusive {}
+    impl Sealed for crate::BoundType {}
+    impl Sealed for crate::Unbounded {}
+}
+
+/// Boundary type, i.e., [`Inclusive`](crate::Inclusive), [`Exclusive`](crate::Exclusive), [`BoundType`](crate::BoundType), or [`Unbounded`](crate::Unbounded).
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Boundary: sealed::Sealed + Eq + Copy {
     fn less<T: PartialOrd>(&self, this: &T, t: &T) -> bool;
+    fn std_bound<'a, T>(&self, limit: &'a T) -> core::ops::Bound<&'a T>;
 }
 
+/// Boundary type usable on the side `LR`, which is [`Left`](crate::Left) or [`Right`](crate::Right).
+/// Name it to write code generic over boundary types. Sealed by [`Boundary`].
+/// ```
+/// use inter_val::{BoundaryOf, Interval, Inclusive, Exclusive, Left, Right};
+/// fn width<L: BoundaryOf<Left>, R: BoundaryOf<Right>>(i: &Interval<f64, L, R>) -> f64 {
+///     i.sup() - i.inf()
+/// }
+/// assert_eq!(width(&Inclusive.at(1.0).to(Exclusive.at(3.5))), 2.5);
+/// ```
 pub trait BoundaryOf<LR>: Boundary {
     type Ordered: Ord;
     fn into_ordered(self) -> Self::Ordered;
 }
+
+/// Scalar types which give the limit of [`Unbounded`](crate::Unbounded) boundaries, i.e., *±∞* of floating point types.
+/// Integer types do not implement it, since they have no infinity and e.g. the measure of a half-line would overflow.
+pub trait Infinity {
+    fn neg_infinity() -> Self;
+    fn infinity() -> Self;
+}